@@ -0,0 +1,71 @@
+use num_traits::{Float, FloatConst};
+
+/// An angle, stored in radians
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+pub struct Angle<T>(T);
+
+impl<T> Angle<T> {
+    pub fn from_radians(radians: T) -> Self {
+        Angle(radians)
+    }
+
+    pub fn radians(&self) -> T
+    where
+        T: Clone,
+    {
+        self.0.clone()
+    }
+}
+
+impl<T: Float + FloatConst> Angle<T> {
+    pub fn from_degrees(degrees: T) -> Self {
+        Angle(degrees.to_radians())
+    }
+
+    pub fn degrees(&self) -> T {
+        self.0.to_degrees()
+    }
+
+    /// Creates an angle from a number of full revolutions, where one turn is 2π radians
+    pub fn from_turns(turns: T) -> Self {
+        Angle(turns * T::TAU())
+    }
+
+    /// Returns the angle as a number of full revolutions
+    pub fn turns(&self) -> T {
+        self.0 / T::TAU()
+    }
+
+    /// Returns the same direction as an angle in `[0, 2π)`
+    pub fn normalized(&self) -> Self {
+        let angle = self.0 % T::TAU();
+        if angle < T::zero() {
+            Angle(angle + T::TAU())
+        } else {
+            Angle(angle)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    fn assert_near(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-12, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn turns() {
+        assert_near(Angle::from_turns(0.25).radians(), Angle::from_degrees(90.0).radians());
+        assert_near(Angle::from_radians(PI).turns(), 0.5);
+    }
+
+    #[test]
+    fn normalized() {
+        assert_near(Angle::from_radians(3.0 * PI).normalized().radians(), PI);
+        assert_near(Angle::from_radians(-PI / 2.0).normalized().radians(), 1.5 * PI);
+        assert_near(Angle::from_radians(0.5).normalized().radians(), 0.5);
+    }
+}
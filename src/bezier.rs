@@ -0,0 +1,156 @@
+use std::fmt;
+
+use num_traits::Float;
+
+use oned::*;
+use twod::*;
+
+/// A cubic Bézier curve from `start` to `end`, shaped by two control points
+pub struct CubicBezier<T, Unit> {
+    pub start: Point<T, Unit>,
+    pub control1: Point<T, Unit>,
+    pub control2: Point<T, Unit>,
+    pub end: Point<T, Unit>,
+}
+
+impl<T, Unit> CubicBezier<T, Unit> {
+    pub fn new(
+        start: Point<T, Unit>,
+        control1: Point<T, Unit>,
+        control2: Point<T, Unit>,
+        end: Point<T, Unit>,
+    ) -> Self {
+        Self { start, control1, control2, end }
+    }
+}
+
+impl<T: Float, Unit> CubicBezier<T, Unit> {
+    /// Returns the point on the curve at parameter `t`, from `start` at 0 to `end` at 1
+    pub fn evaluate(&self, t: T) -> Point<T, Unit> {
+        self.split(t).0.end
+    }
+
+    /// Returns a rect that contains the whole curve.
+    ///
+    /// This is the bounding box of the control points, so it may be larger than the curve itself.
+    pub fn bounding_box(&self) -> Rect<T, Unit> {
+        let min = self.start.component_min(&self.control1).component_min(&self.control2).component_min(&self.end);
+        let max = self.start.component_max(&self.control1).component_max(&self.control2).component_max(&self.end);
+        Rect::from_points(min, max)
+    }
+
+    /// Returns the arc length of the curve, approximated by adaptive subdivision
+    pub fn length(&self) -> Length<T, Unit> {
+        let tolerance = T::from(1e-9).unwrap();
+        Length::new(self.length_within(tolerance, 24))
+    }
+
+    fn length_within(&self, tolerance: T, depth: u32) -> T {
+        let chord = self.start.distance_to(&self.end).get();
+        let polygon = self.start.distance_to(&self.control1).get()
+            + self.control1.distance_to(&self.control2).get()
+            + self.control2.distance_to(&self.end).get();
+
+        if depth == 0 || polygon - chord <= tolerance * polygon {
+            return (chord + polygon) / (T::one() + T::one());
+        }
+
+        let half = T::one() / (T::one() + T::one());
+        let (first, second) = self.split(half);
+        first.length_within(tolerance, depth - 1) + second.length_within(tolerance, depth - 1)
+    }
+
+    /// Splits the curve at `t` into two curves using de Casteljau's algorithm
+    fn split(&self, t: T) -> (Self, Self) {
+        let a = self.start.lerp(&self.control1, t);
+        let b = self.control1.lerp(&self.control2, t);
+        let c = self.control2.lerp(&self.end, t);
+        let ab = a.lerp(&b, t);
+        let bc = b.lerp(&c, t);
+        let middle = ab.lerp(&bc, t);
+
+        (
+            CubicBezier::new(self.start, a, ab, middle),
+            CubicBezier::new(middle, bc, c, self.end),
+        )
+    }
+}
+
+impl<T: Copy, Unit> Copy for CubicBezier<T, Unit> {}
+
+impl<T: Clone, Unit> Clone for CubicBezier<T, Unit> {
+    fn clone(&self) -> Self {
+        CubicBezier {
+            start: self.start.clone(),
+            control1: self.control1.clone(),
+            control2: self.control2.clone(),
+            end: self.end.clone(),
+        }
+    }
+}
+
+impl<T: PartialEq, Unit> PartialEq for CubicBezier<T, Unit> {
+    fn eq(&self, other: &Self) -> bool {
+        self.start == other.start
+            && self.control1 == other.control1
+            && self.control2 == other.control2
+            && self.end == other.end
+    }
+}
+
+impl<T: Eq, Unit> Eq for CubicBezier<T, Unit> {}
+
+impl<T: fmt::Debug, Unit> fmt::Debug for CubicBezier<T, Unit> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "CubicBezier {{ start = {:?}, control1 = {:?}, control2 = {:?}, end = {:?} }}",
+            self.start, self.control1, self.control2, self.end
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    pub use super::*;
+    use test_util::*;
+
+    fn curve() -> CubicBezier<f64, Pixel> {
+        CubicBezier::new(point(0.0, 0.0), point(10.0, 20.0), point(30.0, -10.0), point(40.0, 5.0))
+    }
+
+    #[test]
+    fn evaluate_endpoints() {
+        assert_eq!(curve().evaluate(0.0), point(0.0, 0.0));
+        assert_eq!(curve().evaluate(1.0), point(40.0, 5.0));
+    }
+
+    #[test]
+    fn bounding_box_contains_control_points() {
+        let c = curve();
+        let bounds = c.bounding_box();
+        for p in &[c.start, c.control1, c.control2, c.end] {
+            assert!(bounds.contains(p));
+        }
+        for i in 0..=10 {
+            assert!(bounds.contains(&c.evaluate(f64::from(i) / 10.0)));
+        }
+    }
+
+    #[test]
+    fn length_of_straight_curve() {
+        let line = CubicBezier::new(point(0.0, 0.0), point(1.0, 1.0), point(2.0, 2.0), point(3.0, 3.0));
+        assert!((line.length().get() - 18.0_f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn length_is_between_chord_and_polygon() {
+        let c = curve();
+        let length = c.length().get();
+        assert!(length > c.start.distance_to(&c.end).get());
+        let polygon = c.start.distance_to(&c.control1).get()
+            + c.control1.distance_to(&c.control2).get()
+            + c.control2.distance_to(&c.end).get();
+        assert!(length < polygon);
+    }
+}
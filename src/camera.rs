@@ -0,0 +1,101 @@
+use num_traits::Float;
+
+use twod::*;
+use transform::*;
+
+/// A 2D camera, mapping the world onto a rect on the screen
+pub struct Camera<T, WorldUnit, ScreenUnit> {
+    pub transform: AxisAlignedMatrixTransform<T, T, T, T, T, WorldUnit, ScreenUnit>,
+    pub screen: Rect<T, ScreenUnit>,
+}
+
+impl<T, WorldUnit, ScreenUnit> Camera<T, WorldUnit, ScreenUnit> {
+    pub fn new(
+        transform: AxisAlignedMatrixTransform<T, T, T, T, T, WorldUnit, ScreenUnit>,
+        screen: Rect<T, ScreenUnit>,
+    ) -> Self {
+        Camera { transform, screen }
+    }
+}
+
+impl<T: Float, WorldUnit, ScreenUnit> Camera<T, WorldUnit, ScreenUnit> {
+    /// Returns a camera showing exactly `world` on `screen`
+    pub fn looking_at(world: Rect<T, WorldUnit>, screen: Rect<T, ScreenUnit>) -> Self {
+        Camera::new(AxisAlignedMatrixTransform::from_rects(world, screen), screen)
+    }
+
+    pub fn world_to_screen(&self, p: Point<T, WorldUnit>) -> Point<T, ScreenUnit> {
+        AxisAlignedTransform::transform_point(&self.transform, p)
+    }
+
+    pub fn screen_to_world(&self, p: Point<T, ScreenUnit>) -> Point<T, WorldUnit> {
+        AxisAlignedTransform::transform_point(&self.transform.inverse(), p)
+    }
+
+    /// Returns the part of the world that is shown on the screen
+    pub fn visible_world_rect(&self) -> Rect<T, WorldUnit> {
+        self.transform.inverse().transform_rect(self.screen)
+    }
+
+    /// Moves the view so that the world appears shifted by `offset` on the screen
+    pub fn pan(&mut self, offset: Size<T, ScreenUnit>) {
+        let visible = self.visible_world_rect();
+        let shift = self.transform.inverse().transform_size(offset);
+        self.show(Rect::new(visible.origin - shift, visible.size));
+    }
+
+    /// Magnifies the view by `factor`, keeping the world point under `screen_point` in place
+    pub fn zoom_about(&mut self, screen_point: Point<T, ScreenUnit>, factor: T) {
+        let anchor = self.screen_to_world(screen_point);
+        let visible = self.visible_world_rect();
+        let origin = anchor + (visible.origin - anchor) / factor;
+        self.show(Rect::new(origin, visible.size / factor));
+    }
+
+    fn show(&mut self, world: Rect<T, WorldUnit>) {
+        self.transform = AxisAlignedMatrixTransform::from_rects(world, self.screen);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oned::*;
+
+    struct World;
+    struct Screen;
+
+    fn point<U>(x: f64, y: f64) -> Point<f64, U> {
+        Point::new(PosX::new(x), PosY::new(y))
+    }
+
+    fn rect<U>(x: f64, y: f64, width: f64, height: f64) -> Rect<f64, U> {
+        Rect::new(point(x, y), Size::new(Width::new(width), Height::new(height)))
+    }
+
+    fn camera() -> Camera<f64, World, Screen> {
+        Camera::looking_at(rect(0.0, 0.0, 100.0, 100.0), rect(0.0, 0.0, 400.0, 400.0))
+    }
+
+    #[test]
+    fn world_screen_round_trip() {
+        let camera = camera();
+        assert_eq!(camera.world_to_screen(point(10.0, 20.0)), point(40.0, 80.0));
+        assert_eq!(camera.screen_to_world(point(40.0, 80.0)), point(10.0, 20.0));
+        assert_eq!(camera.visible_world_rect(), rect(0.0, 0.0, 100.0, 100.0));
+    }
+
+    #[test]
+    fn pan_and_zoom() {
+        let mut camera = camera();
+
+        camera.pan(Size::new(Width::new(-40.0), Height::new(0.0)));
+        assert_eq!(camera.visible_world_rect(), rect(10.0, 0.0, 100.0, 100.0));
+
+        let under_cursor = camera.screen_to_world(point(200.0, 100.0));
+        camera.zoom_about(point(200.0, 100.0), 2.0);
+        assert_eq!(camera.visible_world_rect(), rect(35.0, 12.5, 50.0, 50.0));
+        assert_eq!(camera.screen_to_world(point(200.0, 100.0)), under_cursor);
+        assert_eq!(camera.world_to_screen(under_cursor), point(200.0, 100.0));
+    }
+}
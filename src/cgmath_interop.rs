@@ -0,0 +1,53 @@
+//! Conversions to and from the `cgmath` point and vector types.
+
+use cgmath::{Point2, Vector2};
+
+use oned::*;
+use twod::*;
+
+impl<T, Unit> From<Point<T, Unit>> for Point2<T> {
+    fn from(p: Point<T, Unit>) -> Self {
+        Point2::new(p.x.into_inner(), p.y.into_inner())
+    }
+}
+
+impl<T, Unit> From<Point2<T>> for Point<T, Unit> {
+    fn from(p: Point2<T>) -> Self {
+        Point::new(PosX::new(p.x), PosY::new(p.y))
+    }
+}
+
+impl<T, Unit> From<Size<T, Unit>> for Vector2<T> {
+    fn from(s: Size<T, Unit>) -> Self {
+        Vector2::new(s.width.into_inner(), s.height.into_inner())
+    }
+}
+
+impl<T, Unit> From<Vector2<T>> for Size<T, Unit> {
+    fn from(v: Vector2<T>) -> Self {
+        Size::new(Width::new(v.x), Height::new(v.y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct World;
+
+    #[test]
+    fn point_round_trip() {
+        let point: Point<f32, World> = Point::new(PosX::new(1.5), PosY::new(-2.0));
+        let cg: Point2<f32> = point.into();
+        assert_eq!(cg, Point2::new(1.5, -2.0));
+        assert_eq!(Point::from(cg), point);
+    }
+
+    #[test]
+    fn size_round_trip() {
+        let size: Size<f64, World> = Size::new(Width::new(3.0), Height::new(4.0));
+        let cg: Vector2<f64> = size.into();
+        assert_eq!(cg, Vector2::new(3.0, 4.0));
+        assert_eq!(Size::from(cg), size);
+    }
+}
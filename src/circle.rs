@@ -0,0 +1,170 @@
+use std::fmt;
+
+use num_traits::{Float, Num};
+
+use oned::*;
+use twod::*;
+
+/// A circle defined by its center and radius
+pub struct Circle<T, Unit> {
+    pub center: Point<T, Unit>,
+    pub radius: Length<T, Unit>,
+}
+
+impl<T, Unit> Circle<T, Unit> {
+    pub fn new(center: Point<T, Unit>, radius: Length<T, Unit>) -> Self {
+        Self { center, radius }
+    }
+
+    /// Returns true if `p` lies inside the circle or on its boundary
+    pub fn contains(&self, p: &Point<T, Unit>) -> bool
+    where
+        T: Clone + Num + PartialOrd,
+    {
+        let d = p.clone() - self.center.clone();
+        let distance_squared = d.width.get() * d.width.get() + d.height.get() * d.height.get();
+        distance_squared <= self.radius.get() * self.radius.get()
+    }
+
+    /// Returns the smallest circle containing all of `points`, or `None` if there are none.
+    ///
+    /// This is the incremental form of Welzl's algorithm.
+    pub fn enclosing<I: IntoIterator<Item = Point<T, Unit>>>(points: I) -> Option<Self>
+    where
+        T: Float,
+    {
+        let points: Vec<_> = points.into_iter().collect();
+        let mut circle = Circle::new(*points.first()?, Length::new(T::zero()));
+
+        for i in 1..points.len() {
+            if circle.encloses(&points[i]) {
+                continue;
+            }
+            circle = Circle::new(points[i], Length::new(T::zero()));
+            for j in 0..i {
+                if circle.encloses(&points[j]) {
+                    continue;
+                }
+                circle = Circle::from_diameter(points[i], points[j]);
+                for k in 0..j {
+                    if !circle.encloses(&points[k]) {
+                        circle = Circle::circumscribed(points[i], points[j], points[k]);
+                    }
+                }
+            }
+        }
+
+        Some(circle)
+    }
+
+    /// Like `contains`, but tolerant of rounding errors in the radius
+    fn encloses(&self, p: &Point<T, Unit>) -> bool
+    where
+        T: Float,
+    {
+        let d = *p - self.center;
+        let tolerance = T::one() + T::epsilon().sqrt();
+        d.width.get().hypot(d.height.get()) <= self.radius.get() * tolerance
+    }
+
+    fn from_diameter(a: Point<T, Unit>, b: Point<T, Unit>) -> Self
+    where
+        T: Float,
+    {
+        let half = (b - a) / (T::one() + T::one());
+        Circle::new(a + half, Length::new(half.width.get().hypot(half.height.get())))
+    }
+
+    fn circumscribed(a: Point<T, Unit>, b: Point<T, Unit>, c: Point<T, Unit>) -> Self
+    where
+        T: Float,
+    {
+        let (bx, by) = ((b - a).width.get(), (b - a).height.get());
+        let (cx, cy) = ((c - a).width.get(), (c - a).height.get());
+
+        let d = (T::one() + T::one()) * (bx * cy - by * cx);
+        if d == T::zero() {
+            // Collinear points, the circle is spanned by the two farthest apart
+            let candidates = [
+                Circle::from_diameter(a, b),
+                Circle::from_diameter(a, c),
+                Circle::from_diameter(b, c),
+            ];
+            let mut widest = candidates[0];
+            for circle in &candidates[1..] {
+                if circle.radius > widest.radius {
+                    widest = *circle;
+                }
+            }
+            return widest;
+        }
+
+        let b2 = bx * bx + by * by;
+        let c2 = cx * cx + cy * cy;
+        let ux = (cy * b2 - by * c2) / d;
+        let uy = (bx * c2 - cx * b2) / d;
+
+        Circle::new(a + Size::new(Width::new(ux), Height::new(uy)), Length::new(ux.hypot(uy)))
+    }
+}
+
+impl<T: Copy, Unit> Copy for Circle<T, Unit> {}
+
+impl<T: Clone, Unit> Clone for Circle<T, Unit> {
+    fn clone(&self) -> Self {
+        Circle {
+            center: self.center.clone(),
+            radius: self.radius.clone(),
+        }
+    }
+}
+
+impl<T: PartialEq, Unit> PartialEq for Circle<T, Unit> {
+    fn eq(&self, other: &Self) -> bool {
+        self.center == other.center && self.radius == other.radius
+    }
+}
+
+impl<T: Eq, Unit> Eq for Circle<T, Unit> {}
+
+impl<T: fmt::Debug, Unit> fmt::Debug for Circle<T, Unit> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Circle {{ center = {:?}, radius = {:?} }}", self.center, self.radius)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    pub use super::*;
+    use test_util::*;
+
+    #[test]
+    fn enclosing_empty() {
+        let points: Vec<Point<f64, Pixel>> = vec![];
+        assert_eq!(Circle::enclosing(points), None);
+    }
+
+    #[test]
+    fn enclosing_one_and_two_points() {
+        assert_eq!(
+            Circle::enclosing(vec![point(3.0, 4.0)]),
+            Some(Circle::new(point(3.0, 4.0), Length::new(0.0)))
+        );
+        assert_eq!(
+            Circle::enclosing(vec![point(0.0, 0.0), point(6.0, 8.0)]),
+            Some(Circle::new(point(3.0, 4.0), Length::new(5.0)))
+        );
+    }
+
+    #[test]
+    fn enclosing_triangle() {
+        let points = vec![point(0.0, 0.0), point(4.0, 0.0), point(1.0, 3.0)];
+        let circle = Circle::enclosing(points.clone()).unwrap();
+
+        for p in points {
+            let d = p - circle.center;
+            assert!(d.width.get().hypot(d.height.get()) <= circle.radius.get() + 1e-9);
+        }
+        assert!((circle.radius.get() - 5.0_f64.sqrt()).abs() < 1e-9);
+    }
+}
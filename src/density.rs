@@ -0,0 +1,36 @@
+//! Conversions between density-independent pixels and physical pixels.
+
+use oned::*;
+
+/// The unit of density-independent pixels
+pub struct Dp;
+
+/// The unit of physical pixels
+pub struct Px;
+
+/// Converts a width in density-independent pixels to physical pixels.
+///
+/// The `density` is the number of physical pixels per density-independent pixel,
+/// e.g. 2.0 for the Android `xhdpi` bucket.
+pub fn dp_to_px(dp: Width<f64, Dp>, density: f64) -> Width<f64, Px> {
+    Width::new(dp.into_inner() * density)
+}
+
+/// Converts a width in physical pixels to density-independent pixels.
+///
+/// This is the inverse of `dp_to_px` with the same `density`.
+pub fn px_to_dp(px: Width<f64, Px>, density: f64) -> Width<f64, Dp> {
+    Width::new(px.into_inner() / density)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dp_px_round_trip() {
+        let px = dp_to_px(Width::new(48.0), 2.0);
+        assert_eq!(px, Width::new(96.0));
+        assert_eq!(px_to_dp(px, 2.0), Width::new(48.0));
+    }
+}
@@ -0,0 +1,214 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+use num_traits::Float;
+
+use oned::*;
+use twod::*;
+
+/// The integer coordinate of a cell in a `Grid`
+///
+/// This is kept apart from `Point` so that cell indices can not be mixed up with positions.
+pub struct GridPos<T, Unit> {
+    pub column: T,
+    pub row: T,
+    unit: PhantomData<Unit>,
+}
+
+impl<T, Unit> GridPos<T, Unit> {
+    pub fn new(column: T, row: T) -> Self {
+        GridPos {
+            column,
+            row,
+            unit: PhantomData {},
+        }
+    }
+}
+
+impl<T: Copy, Unit> Copy for GridPos<T, Unit> {}
+
+impl<T: Clone, Unit> Clone for GridPos<T, Unit> {
+    fn clone(&self) -> Self {
+        GridPos::new(self.column.clone(), self.row.clone())
+    }
+}
+
+impl<T: PartialEq, Unit> PartialEq for GridPos<T, Unit> {
+    fn eq(&self, other: &Self) -> bool {
+        self.column == other.column && self.row == other.row
+    }
+}
+
+impl<T: Eq, Unit> Eq for GridPos<T, Unit> {}
+
+impl<T: Hash, Unit> Hash for GridPos<T, Unit> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.column.hash(state);
+        self.row.hash(state);
+    }
+}
+
+impl<T: fmt::Debug, Unit> fmt::Debug for GridPos<T, Unit> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "GridPos {{ column = {:?}, row = {:?} }}", self.column, self.row)
+    }
+}
+
+/// A regular grid of equally sized cells, with cell `(0, 0)` starting at `origin`
+pub struct Grid<T, Unit> {
+    pub origin: Point<T, Unit>,
+    pub cell_size: Size<T, Unit>,
+}
+
+impl<T, Unit> Grid<T, Unit> {
+    pub fn new(origin: Point<T, Unit>, cell_size: Size<T, Unit>) -> Self {
+        Grid { origin, cell_size }
+    }
+}
+
+impl<T: Float, Unit> Grid<T, Unit> {
+    /// Returns the top-left corner of the cell at `pos`
+    ///
+    /// # Panics
+    ///
+    /// Panics if a cell index can not be represented in `T`.
+    pub fn cell_origin(&self, pos: &GridPos<i32, Unit>) -> Point<T, Unit> {
+        let to_t = |value: i32| T::from(value).expect("cell index out of range");
+        Point::new(
+            self.origin.x + self.cell_size.width * to_t(pos.column),
+            self.origin.y + self.cell_size.height * to_t(pos.row),
+        )
+    }
+
+    /// Returns the cell containing `point`
+    ///
+    /// Points on the boundary between two cells belong to the one with the larger index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a coordinate of `point` is NaN, or if the cell index does not fit in an `i32`.
+    pub fn cell_at(&self, point: &Point<T, Unit>) -> GridPos<i32, Unit> {
+        let offset = *point - self.origin;
+        let to_i32 = |value: T| value.floor().to_i32().expect("cell index out of i32 range");
+        GridPos::new(
+            to_i32(offset.width.get() / self.cell_size.width.get()),
+            to_i32(offset.height.get() / self.cell_size.height.get()),
+        )
+    }
+
+    /// Iterates row by row over every cell that `rect` overlaps.
+    ///
+    /// Cells are included even if the rect only covers part of them, but not if it only touches
+    /// their edge. A rect with no area still yields the cell containing its origin.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a coordinate of `rect` is NaN, or if a cell index does not fit in an `i32`.
+    pub fn cells_in_rect(&self, rect: &Rect<T, Unit>) -> impl Iterator<Item = GridPos<i32, Unit>> {
+        let first = self.cell_at(&rect.origin);
+        let offset = rect.corner() - self.origin;
+        let to_i32 = |value: T| value.ceil().to_i32().expect("cell index out of i32 range");
+        let last = GridPos::<i32, Unit>::new(
+            (to_i32(offset.width.get() / self.cell_size.width.get()) - 1).max(first.column),
+            (to_i32(offset.height.get() / self.cell_size.height.get()) - 1).max(first.row),
+        );
+        (first.row..=last.row).flat_map(move |row| {
+            (first.column..=last.column).map(move |column| GridPos::new(column, row))
+        })
+    }
+}
+
+impl<T: Float, Unit> Point<T, Unit> {
+    /// Moves the point to the nearest corner of a cell in `grid`
+    pub fn snap_to_grid(&self, grid: &Grid<T, Unit>) -> Point<T, Unit> {
+        let offset = *self - grid.origin;
+        let snap = |value: T, cell: T| (value / cell).round() * cell;
+        grid.origin
+            + Size::new(
+                Width::new(snap(offset.width.get(), grid.cell_size.width.get())),
+                Height::new(snap(offset.height.get(), grid.cell_size.height.get())),
+            )
+    }
+}
+
+impl<T: Copy, Unit> Copy for Grid<T, Unit> {}
+
+impl<T: Clone, Unit> Clone for Grid<T, Unit> {
+    fn clone(&self) -> Self {
+        Grid {
+            origin: self.origin.clone(),
+            cell_size: self.cell_size.clone(),
+        }
+    }
+}
+
+impl<T: PartialEq, Unit> PartialEq for Grid<T, Unit> {
+    fn eq(&self, other: &Self) -> bool {
+        self.origin == other.origin && self.cell_size == other.cell_size
+    }
+}
+
+impl<T: fmt::Debug, Unit> fmt::Debug for Grid<T, Unit> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Grid {{ origin = {:?}, cell_size = {:?} }}", self.origin, self.cell_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_util::*;
+
+    fn grid(x: f64, y: f64, cell: f64) -> Grid<f64, Pixel> {
+        Grid::new(
+            Point::new(PosX::new(x), PosY::new(y)),
+            Size::new(Width::new(cell), Height::new(cell)),
+        )
+    }
+
+    #[test]
+    fn cell_at() {
+        let g = grid(0.0, 0.0, 10.0);
+        assert_eq!(g.cell_at(&point(45.0, 75.0)), GridPos::new(4, 7));
+        assert_eq!(g.cell_at(&point(40.0, 70.0)), GridPos::new(4, 7));
+        assert_eq!(g.cell_at(&point(-5.0, 0.0)), GridPos::new(-1, 0));
+    }
+
+    #[test]
+    fn cell_origin() {
+        let g = grid(5.0, -5.0, 10.0);
+        assert_eq!(g.cell_origin(&GridPos::new(4, 7)), point(45.0, 65.0));
+        assert_eq!(g.cell_at(&g.cell_origin(&GridPos::new(4, 7))), GridPos::new(4, 7));
+    }
+
+    #[test]
+    fn cells_in_rect() {
+        let g = grid(0.0, 0.0, 10.0);
+        let r = Rect::new(point(5.0, 15.0), Size::new(Width::new(10.0), Height::new(10.0)));
+
+        let cells: Vec<_> = g.cells_in_rect(&r).collect();
+        assert_eq!(
+            cells,
+            vec![GridPos::new(0, 1), GridPos::new(1, 1), GridPos::new(0, 2), GridPos::new(1, 2)]
+        );
+    }
+
+    #[test]
+    fn cells_in_rect_on_boundaries() {
+        let g = grid(0.0, 0.0, 10.0);
+
+        let exact = Rect::new(point(0.0, 0.0), Size::new(Width::new(10.0), Height::new(10.0)));
+        assert_eq!(g.cells_in_rect(&exact).collect::<Vec<_>>(), vec![GridPos::new(0, 0)]);
+
+        let empty = Rect::new(point(10.0, 10.0), Size::new(Width::new(0.0), Height::new(0.0)));
+        assert_eq!(g.cells_in_rect(&empty).collect::<Vec<_>>(), vec![GridPos::new(1, 1)]);
+    }
+
+    #[test]
+    fn snap_to_grid() {
+        let g = grid(0.0, 0.0, 10.0);
+        assert_eq!(point(23.0, 37.0).snap_to_grid(&g), point(20.0, 40.0));
+        assert_eq!(point(-3.0, 7.0).snap_to_grid(&grid(1.0, 1.0, 10.0)), point(1.0, 11.0));
+    }
+}
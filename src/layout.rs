@@ -0,0 +1,172 @@
+use std::ops::Mul;
+
+use num_traits::{Float, One, Zero};
+
+use oned::*;
+use twod::*;
+
+/// Where to align a smaller item inside a larger one.
+///
+/// The top edge is the one with the smallest y coordinate.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Anchor {
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+impl Anchor {
+    /// Returns how far along each axis the anchor lies, from 0 at the start to 1 at the end
+    fn fractions<T: Float>(self) -> (T, T) {
+        let half = T::one() / (T::one() + T::one());
+        match self {
+            Anchor::TopLeft => (T::zero(), T::zero()),
+            Anchor::Top => (half, T::zero()),
+            Anchor::TopRight => (T::one(), T::zero()),
+            Anchor::Left => (T::zero(), half),
+            Anchor::Center => (half, half),
+            Anchor::Right => (T::one(), half),
+            Anchor::BottomLeft => (T::zero(), T::one()),
+            Anchor::Bottom => (half, T::one()),
+            Anchor::BottomRight => (T::one(), T::one()),
+        }
+    }
+}
+
+/// How to scale an item to fit inside a box, following CSS `object-fit`
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Fit {
+    /// Scale uniformly so the whole item is visible inside the box
+    Contain,
+    /// Scale uniformly so the item covers the whole box, possibly overflowing it
+    Cover,
+    /// Stretch the item to exactly the size of the box
+    Fill,
+    /// Like `Contain`, but never scale the item up
+    ScaleDown,
+}
+
+/// A fraction between zero and one, such as "40% of the parent width"
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+pub struct Fraction<T>(T);
+
+impl<T> Fraction<T> {
+    /// Creates a fraction, clamping `value` to the range `[0, 1]`
+    pub fn new(value: T) -> Self
+    where
+        T: PartialOrd + Zero + One,
+    {
+        if value < T::zero() {
+            Fraction(T::zero())
+        } else if value > T::one() {
+            Fraction(T::one())
+        } else {
+            Fraction(value)
+        }
+    }
+
+    pub fn get(&self) -> T
+    where
+        T: Clone,
+    {
+        self.0.clone()
+    }
+
+    /// Returns this fraction of `length`
+    pub fn of<Unit>(&self, length: Width<T, Unit>) -> Width<T, Unit>
+    where
+        T: Clone + Mul<T, Output = T>,
+    {
+        length * self.get()
+    }
+}
+
+impl<T: Float, Unit> Size<T, Unit> {
+    /// Returns the rect this size occupies when fitted into `bounds` and aligned by `anchor`
+    pub fn place_in(&self, bounds: &Rect<T, Unit>, fit: Fit, anchor: Anchor) -> Rect<T, Unit> {
+        let scale_x = bounds.size.width.get() / self.width.get();
+        let scale_y = bounds.size.height.get() / self.height.get();
+
+        let size = match fit {
+            Fit::Contain => *self * scale_x.min(scale_y),
+            Fit::Cover => *self * scale_x.max(scale_y),
+            Fit::Fill => bounds.size,
+            Fit::ScaleDown => *self * scale_x.min(scale_y).min(T::one()),
+        };
+
+        Rect::new(bounds.origin + size.offset_within(&bounds.size, anchor), size)
+    }
+
+    /// Returns the displacement from the container's origin that places this size at `anchor`
+    pub fn offset_within(&self, container: &Size<T, Unit>, anchor: Anchor) -> Size<T, Unit> {
+        let (fraction_x, fraction_y) = anchor.fractions();
+        let free = *container - *self;
+        Size {
+            width: Width::new(free.width.get() * fraction_x),
+            height: Height::new(free.height.get() * fraction_y),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    pub use super::*;
+    use test_util::*;
+
+    #[test]
+    fn place_contain() {
+        let bounds = rect(0.0, 0.0, 100.0, 100.0);
+        let placed = size(400.0, 200.0).place_in(&bounds, Fit::Contain, Anchor::Center);
+        assert_eq!(placed, rect(0.0, 25.0, 100.0, 50.0));
+    }
+
+    #[test]
+    fn place_cover() {
+        let bounds = rect(0.0, 0.0, 100.0, 100.0);
+        let placed = size(400.0, 200.0).place_in(&bounds, Fit::Cover, Anchor::Center);
+        assert_eq!(placed, rect(-50.0, 0.0, 200.0, 100.0));
+    }
+
+    #[test]
+    fn place_fill() {
+        let bounds = rect(0.0, 0.0, 100.0, 100.0);
+        let placed = size(400.0, 200.0).place_in(&bounds, Fit::Fill, Anchor::Center);
+        assert_eq!(placed, rect(0.0, 0.0, 100.0, 100.0));
+    }
+
+    #[test]
+    fn place_scale_down() {
+        let bounds = rect(0.0, 0.0, 100.0, 100.0);
+        let placed = size(400.0, 200.0).place_in(&bounds, Fit::ScaleDown, Anchor::Center);
+        assert_eq!(placed, rect(0.0, 25.0, 100.0, 50.0));
+
+        let placed = size(50.0, 20.0).place_in(&bounds, Fit::ScaleDown, Anchor::BottomRight);
+        assert_eq!(placed, rect(50.0, 80.0, 50.0, 20.0));
+    }
+
+    #[test]
+    fn offset_within_center() {
+        let offset = size(40.0, 20.0).offset_within(&size(100.0, 80.0), Anchor::Center);
+        assert_eq!(offset, size(30.0, 30.0));
+    }
+
+    #[test]
+    fn offset_within_bottom_right() {
+        let offset = size(40.0, 20.0).offset_within(&size(100.0, 80.0), Anchor::BottomRight);
+        assert_eq!(offset, size(60.0, 60.0));
+    }
+
+    #[test]
+    fn fraction_of() {
+        let parent: Width<f64, Pixel> = Width::new(200.0);
+        assert_eq!(Fraction::new(0.4).of(parent), Width::new(80.0));
+        assert_eq!(Fraction::new(1.5).of(parent), parent);
+        assert_eq!(Fraction::new(-0.5).get(), 0.0);
+    }
+}
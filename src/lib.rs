@@ -44,13 +44,61 @@
 //! let p3 = p1 + p2;
 //! ```
 
+extern crate num_traits;
+#[cfg(feature = "ordered-float")]
+extern crate ordered_float;
+#[cfg(feature = "winit")]
+extern crate dpi;
+#[cfg(feature = "cgmath")]
+extern crate cgmath;
+
+mod scalar;
+mod angle;
 mod oned;
 mod twod;
+mod segment;
+mod circle;
+mod bezier;
+mod path;
+mod polygon;
+mod layout;
+mod density;
+mod grid;
+mod rect_index;
+mod rounded_rect;
 mod transform;
+mod camera;
+#[cfg(feature = "ordered-float")]
+mod ordered;
+#[cfg(feature = "winit")]
+mod winit;
+#[cfg(feature = "cgmath")]
+mod cgmath_interop;
+#[cfg(feature = "svg")]
+mod svg;
+#[cfg(test)]
+mod test_util;
 
+pub use scalar::*;
+pub use angle::*;
 pub use oned::*;
 pub use twod::*;
+pub use segment::*;
+pub use circle::*;
+pub use bezier::*;
+pub use path::*;
+pub use polygon::*;
+pub use layout::*;
+pub use density::*;
+pub use grid::*;
+pub use rect_index::*;
+pub use rounded_rect::*;
 pub use transform::*;
+pub use camera::*;
+#[cfg(feature = "winit")]
+pub use winit::*;
+#[cfg(feature = "svg")]
+pub use svg::*;
 
 #[cfg(test)]
 mod tests {
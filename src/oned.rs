@@ -3,11 +3,75 @@ use std::clone::Clone;
 use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign};
 use std::cmp::Ordering;
 use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter;
+
+use num_traits::{CheckedAdd, Float, SaturatingAdd, Zero};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// The operations shared by all one-dimensional types, for writing generic code over them.
+///
+/// This trait is sealed and can not be implemented outside of this crate.
+pub trait OneDimensional<T, Unit>: sealed::Sealed + Sized {
+    /// Creates a value with a specified scalar and unit
+    fn new(x: T) -> Self;
+
+    /// Returns the scalar value without a unit
+    fn get(&self) -> T
+    where
+        T: Clone;
+
+    /// Returns the scalar value without a unit, consuming the value
+    fn into_inner(self) -> T;
+}
+
+/// A fixed ratio between two units, declared once to allow converting values from `From` to `To`.
+///
+/// Implement it for `()`, for example
+///
+/// ```
+/// use planar::{UnitConversion, Width};
+/// struct Cm;
+/// struct Mm;
+///
+/// impl UnitConversion<Cm, Mm> for () {
+///     const RATIO: f64 = 10.0;
+/// }
+///
+/// let w: Width<f64, Mm> = Width::<f64, Cm>::new(5.0).convert();
+/// assert_eq!(w, Width::new(50.0));
+/// ```
+pub trait UnitConversion<From, To> {
+    /// The number of `To` units in one `From` unit
+    const RATIO: f64;
+}
 
 macro_rules! impl_oned {
     ($(#[$attr:meta])* $s:ident) => {
         $(#[$attr])* pub struct $s<T, Unit> (T, PhantomData<Unit>);
 
+        impl<T, Unit> sealed::Sealed for $s<T, Unit> {}
+
+        impl<T, Unit> OneDimensional<T, Unit> for $s<T, Unit> {
+            fn new(x: T) -> Self {
+                $s(x, PhantomData {})
+            }
+
+            fn get(&self) -> T
+            where
+                T: Clone,
+            {
+                self.0.clone()
+            }
+
+            fn into_inner(self) -> T {
+                self.0
+            }
+        }
+
         impl<T: Copy, Unit> Copy for $s<T, Unit> {}
 
         impl<T: Clone, Unit> Clone for $s<T, Unit> {
@@ -30,6 +94,13 @@ macro_rules! impl_oned {
             }
         }
 
+        /// Retags the value with a different unit without changing it
+        impl<T, Unit> $s<T, Unit> {
+            pub fn map_unit<NewUnit>(self) -> $s<T, NewUnit> {
+                $s::new(self.0)
+            }
+        }
+
         /// Returns the scalar value without a unit
         impl<T: Clone, Unit> $s<T, Unit> {
             pub fn get(&self) -> T {
@@ -90,9 +161,15 @@ macro_rules! impl_oned {
             }
         }
 
+        impl<T: Hash, Unit> Hash for $s<T, Unit> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.0.hash(state)
+            }
+        }
+
         impl<T: fmt::Debug, Unit> fmt::Debug for $s<T, Unit> {
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                write!(f, "OneD {{ {:?} }}", self.0)
+                write!(f, "{}({:?})", stringify!($s), self.0)
             }
         }
     }
@@ -106,6 +183,11 @@ impl_oned!(
 impl_oned!(Width);
 impl_oned!(Height);
 
+impl_oned!(
+    /// An area, the product of two lengths
+    Area
+);
+
 impl_oned!(Position);
 impl_oned!(PosX);
 impl_oned!(PosY);
@@ -153,6 +235,7 @@ macro_rules! impl_oned_add {
             }
         }
 
+        /// Subtracting two positions is the only way to turn positions into a length
         impl<T: Sub<V, Output = W>, V, W, Unit> Sub<$pos<V, Unit>> for $pos<T, Unit> {
             type Output = $length<W, Unit>;
             fn sub(self, other: $pos<V, Unit>) -> Self::Output {
@@ -171,6 +254,145 @@ macro_rules! impl_oned_add {
                 self.0 -= other.into_inner()
             }
         }
+
+        /// Converts the length to another unit with a declared `UnitConversion`
+        impl<T: Float, Unit> $length<T, Unit> {
+            pub fn convert<To>(self) -> $length<T, To>
+            where
+                (): UnitConversion<Unit, To>,
+            {
+                let ratio = T::from(<() as UnitConversion<Unit, To>>::RATIO)
+                    .expect("conversion ratio out of range");
+                $length::new(self.0 * ratio)
+            }
+        }
+
+        /// Converts the position to another unit with a declared `UnitConversion`.
+        /// Both units share the same origin.
+        impl<T: Float, Unit> $pos<T, Unit> {
+            pub fn convert<To>(self) -> $pos<T, To>
+            where
+                (): UnitConversion<Unit, To>,
+            {
+                let ratio = T::from(<() as UnitConversion<Unit, To>>::RATIO)
+                    .expect("conversion ratio out of range");
+                $pos::new(self.0 * ratio)
+            }
+        }
+
+        /// Compares the length against zero
+        impl<T: PartialOrd + Zero, Unit> $length<T, Unit> {
+            pub fn is_positive(&self) -> bool {
+                self.0 > T::zero()
+            }
+
+            pub fn is_negative(&self) -> bool {
+                self.0 < T::zero()
+            }
+
+            pub fn is_zero(&self) -> bool {
+                self.0 == T::zero()
+            }
+        }
+
+        /// Adds two lengths, returning `None` on overflow
+        impl<T: CheckedAdd, Unit> $length<T, Unit> {
+            pub fn checked_add(&self, other: &$length<T, Unit>) -> Option<Self> {
+                self.0.checked_add(&other.0).map($length::new)
+            }
+        }
+
+        /// Adds two lengths, clamping to the bounds of the scalar type on overflow
+        impl<T: SaturatingAdd, Unit> $length<T, Unit> {
+            pub fn saturating_add(&self, other: &$length<T, Unit>) -> Self {
+                $length::new(self.0.saturating_add(&other.0))
+            }
+        }
+
+        /// Offsets a position by a length, returning `None` on overflow
+        impl<T: CheckedAdd, Unit> $pos<T, Unit> {
+            pub fn checked_add(&self, other: &$length<T, Unit>) -> Option<Self> {
+                self.0.checked_add(&other.0).map($pos::new)
+            }
+        }
+
+        /// Offsets a position by a length, clamping to the bounds of the scalar type on overflow
+        impl<T: SaturatingAdd, Unit> $pos<T, Unit> {
+            pub fn saturating_add(&self, other: &$length<T, Unit>) -> Self {
+                $pos::new(self.0.saturating_add(&other.0))
+            }
+        }
+
+        /// Returns the length from this position to `other`, the same as `other - self`
+        impl<T: Clone + Sub<T, Output = T>, Unit> $pos<T, Unit> {
+            pub fn displacement_to(&self, other: &$pos<T, Unit>) -> $length<T, Unit> {
+                other.clone() - self.clone()
+            }
+        }
+
+        /// Iterates from this position up to and including `end` in increments of `step`.
+        /// A zero or negative step yields nothing.
+        ///
+        /// No position past `end` is ever computed, so integer positions do not overflow
+        /// even when `end` is close to the largest value. For floats, each position is the
+        /// previous one plus `step`, so rounding errors can build up over many steps, and `end`
+        /// is left out if it is not reached exactly. For example, stepping from `0.0` to `0.3`
+        /// by `0.1` stops at `0.2`.
+        impl<T, Unit> $pos<T, Unit> {
+            pub fn step_to(&self, end: $pos<T, Unit>, step: $length<T, Unit>)
+                -> impl Iterator<Item = $pos<T, Unit>>
+            where
+                T: Clone + PartialOrd + Zero + Add<T, Output = T> + Sub<T, Output = T>,
+            {
+                let first = if step.get() > T::zero() && *self <= end {
+                    Some(self.clone())
+                } else {
+                    None
+                };
+                iter::successors(first, move |pos| {
+                    // Compare without overflowing: `end - pos` only for a non-negative `pos`,
+                    // and `pos + step` only for a negative one
+                    let fits = if pos.get() >= T::zero() {
+                        end.clone() - pos.clone() >= step
+                    } else {
+                        pos.clone() + step.clone() <= end
+                    };
+                    if fits { Some(pos.clone() + step.clone()) } else { None }
+                })
+            }
+        }
+    }
+}
+
+// These can not be generic over the scalar, since they would overlap with scaling by any `V`
+macro_rules! impl_area_mul {
+    ($($t:ty)*) => {
+        $(
+            /// Multiplies two lengths into the area of a rectangle with those sides
+            impl<Unit> Mul<Length<$t, Unit>> for Length<$t, Unit> {
+                type Output = Area<$t, Unit>;
+                fn mul(self, other: Length<$t, Unit>) -> Self::Output {
+                    Area::new(self.into_inner() * other.into_inner())
+                }
+            }
+
+            /// Multiplies a width and a height into the area of a rectangle with those sides
+            impl<Unit> Mul<Height<$t, Unit>> for Width<$t, Unit> {
+                type Output = Area<$t, Unit>;
+                fn mul(self, other: Height<$t, Unit>) -> Self::Output {
+                    Area::new(self.into_inner() * other.into_inner())
+                }
+            }
+        )*
+    }
+}
+
+impl_area_mul!(f32 f64 i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+/// Returns the side of the square with this area
+impl<T: Float, Unit> Area<T, Unit> {
+    pub fn sqrt(&self) -> Length<T, Unit> {
+        Length::new(self.0.sqrt())
     }
 }
 
@@ -178,6 +400,50 @@ impl_oned_add!(Length, Position);
 impl_oned_add!(Width, PosX);
 impl_oned_add!(Height, PosY);
 
+/// A half-open interval `[start, end)` between two positions
+pub struct Interval<T, Unit> {
+    pub start: Position<T, Unit>,
+    pub end: Position<T, Unit>,
+}
+
+impl<T, Unit> Interval<T, Unit> {
+    pub fn new(start: Position<T, Unit>, end: Position<T, Unit>) -> Self {
+        Interval { start, end }
+    }
+}
+
+/// Iterates over every integer position in the interval, excluding the end
+impl<Unit> Interval<i32, Unit> {
+    pub fn positions(&self) -> impl Iterator<Item = Position<i32, Unit>> {
+        (self.start.get()..self.end.get()).map(Position::new)
+    }
+}
+
+impl<T: Copy, Unit> Copy for Interval<T, Unit> {}
+
+impl<T: Clone, Unit> Clone for Interval<T, Unit> {
+    fn clone(&self) -> Self {
+        Interval {
+            start: self.start.clone(),
+            end: self.end.clone(),
+        }
+    }
+}
+
+impl<T: PartialEq, Unit> PartialEq for Interval<T, Unit> {
+    fn eq(&self, other: &Self) -> bool {
+        self.start == other.start && self.end == other.end
+    }
+}
+
+impl<T: Eq, Unit> Eq for Interval<T, Unit> {}
+
+impl<T: fmt::Debug, Unit> fmt::Debug for Interval<T, Unit> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Interval {{ start = {:?}, end = {:?} }}", self.start, self.end)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     pub use super::*;
@@ -193,4 +459,139 @@ mod tests {
         assert_eq!(w.into_inner(), 40.0);
         assert_eq!(w2.into_inner(), 20.0);
     }
+
+    #[test]
+    fn interval_positions() {
+        let interval: Interval<i32, Pixel> = Interval::new(Position::new(3), Position::new(7));
+        let positions: Vec<_> = interval.positions().collect();
+        assert_eq!(
+            positions,
+            vec![
+                Position::new(3),
+                Position::new(4),
+                Position::new(5),
+                Position::new(6),
+            ]
+        );
+    }
+
+    #[test]
+    fn displacement_to() {
+        let a: Position<f64, Pixel> = Position::new(3.0);
+        let b = Position::new(10.0);
+        assert_eq!(a.displacement_to(&b), b - a);
+        assert_eq!(a.displacement_to(&b), Length::new(7.0));
+    }
+
+    #[test]
+    fn sign_checks() {
+        let positive: Width<f64, Pixel> = Width::new(3.0);
+        let negative: Width<f64, Pixel> = Width::new(-3.0);
+        let zero: Width<f64, Pixel> = Width::new(0.0);
+
+        assert!(positive.is_positive() && !positive.is_negative() && !positive.is_zero());
+        assert!(!negative.is_positive() && negative.is_negative() && !negative.is_zero());
+        assert!(!zero.is_positive() && !zero.is_negative() && zero.is_zero());
+    }
+
+    fn round<D: OneDimensional<f64, Pixel>>(value: D) -> D {
+        D::new(value.get().round())
+    }
+
+    #[test]
+    fn generic_one_dimensional() {
+        assert_eq!(round(Width::new(2.4)), Width::new(2.0));
+        assert_eq!(round(PosY::new(-1.6)), PosY::new(-2.0));
+        assert_eq!(round(Length::new(0.5)).into_inner(), 1.0);
+    }
+
+    #[test]
+    fn debug_names_the_type() {
+        assert_eq!(format!("{:?}", Width::<f64, Pixel>::new(40.0)), "Width(40.0)");
+        assert_eq!(format!("{:?}", Height::<f64, Pixel>::new(20.0)), "Height(20.0)");
+        assert_eq!(format!("{:?}", PosX::<i32, Pixel>::new(3)), "PosX(3)");
+        assert_eq!(format!("{:?}", PosY::<i32, Pixel>::new(-4)), "PosY(-4)");
+    }
+
+    #[test]
+    fn area() {
+        let area: Area<f64, Pixel> = Length::new(3.0) * Length::new(4.0);
+        assert_eq!(area, Area::new(12.0));
+        assert_eq!(Width::<i32, Pixel>::new(3) * Height::new(5), Area::new(15));
+        assert_eq!(Area::<f64, Pixel>::new(9.0).sqrt(), Length::new(3.0));
+        assert_eq!(Length::<f64, Pixel>::new(3.0) * 2.0, Length::new(6.0));
+    }
+
+    #[test]
+    fn convert() {
+        struct Cm;
+        struct Mm;
+        impl UnitConversion<Cm, Mm> for () {
+            const RATIO: f64 = 10.0;
+        }
+
+        assert_eq!(Width::<f64, Cm>::new(5.0).convert::<Mm>(), Width::new(50.0));
+        assert_eq!(Length::<f32, Cm>::new(5.0).convert::<Mm>(), Length::new(50.0));
+        assert_eq!(PosY::<f64, Cm>::new(-1.5).convert::<Mm>(), PosY::new(-15.0));
+    }
+
+    #[test]
+    fn step_to_float_rounding() {
+        // 0.3 - 0.2 is slightly below 0.1 in floating point, so the end is not reached
+        let start: PosX<f64, Pixel> = PosX::new(0.0);
+        let ticks: Vec<_> = start.step_to(PosX::new(0.3), Width::new(0.1)).collect();
+        assert_eq!(ticks.len(), 3);
+    }
+
+    #[test]
+    fn step_to_near_integer_bounds() {
+        let start: PosX<i32, Pixel> = PosX::new(i32::MAX - 25);
+        let ticks: Vec<_> = start.step_to(PosX::new(i32::MAX), Width::new(10)).collect();
+        assert_eq!(
+            ticks,
+            vec![PosX::new(i32::MAX - 25), PosX::new(i32::MAX - 15), PosX::new(i32::MAX - 5)]
+        );
+
+        let start: PosY<i32, Pixel> = PosY::new(i32::MIN);
+        let ticks: Vec<_> = start.step_to(PosY::new(i32::MAX), Height::new(i32::MAX)).collect();
+        assert_eq!(ticks, vec![PosY::new(i32::MIN), PosY::new(-1), PosY::new(i32::MAX - 1)]);
+
+        let start: PosX<u8, Pixel> = PosX::new(250);
+        assert_eq!(start.step_to(PosX::new(255), Width::new(5)).count(), 2);
+    }
+
+    #[test]
+    fn step_to() {
+        let start: PosX<f64, Pixel> = PosX::new(0.0);
+        let ticks: Vec<_> = start.step_to(PosX::new(10.0), Width::new(2.5)).collect();
+        assert_eq!(
+            ticks,
+            vec![
+                PosX::new(0.0),
+                PosX::new(2.5),
+                PosX::new(5.0),
+                PosX::new(7.5),
+                PosX::new(10.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn step_to_non_positive_step() {
+        let start: PosX<f64, Pixel> = PosX::new(0.0);
+        assert_eq!(start.step_to(PosX::new(10.0), Width::new(0.0)).count(), 0);
+        assert_eq!(start.step_to(PosX::new(10.0), Width::new(-1.0)).count(), 0);
+    }
+
+    #[test]
+    fn checked_and_saturating_add() {
+        let w: Width<i32, Pixel> = Width::new(i32::MAX - 1);
+        assert_eq!(w.checked_add(&Width::new(1)), Some(Width::new(i32::MAX)));
+        assert_eq!(w.checked_add(&Width::new(2)), None);
+        assert_eq!(w.saturating_add(&Width::new(2)), Width::new(i32::MAX));
+
+        let x: PosX<i32, Pixel> = PosX::new(i32::MIN + 1);
+        assert_eq!(x.checked_add(&Width::new(-2)), None);
+        assert_eq!(x.saturating_add(&Width::new(-2)), PosX::new(i32::MIN));
+    }
 }
@@ -0,0 +1,58 @@
+//! Conversions to and from `OrderedFloat`, so float geometry can be sorted and hashed.
+
+use ordered_float::OrderedFloat;
+
+use oned::*;
+use twod::*;
+
+impl<T, Unit> Point<T, Unit> {
+    /// Wraps both coordinates in `OrderedFloat`
+    pub fn into_ordered(self) -> Point<OrderedFloat<T>, Unit> {
+        Point::new(
+            PosX::new(OrderedFloat(self.x.into_inner())),
+            PosY::new(OrderedFloat(self.y.into_inner())),
+        )
+    }
+
+    /// Unwraps both coordinates from `OrderedFloat`
+    pub fn from_ordered(p: Point<OrderedFloat<T>, Unit>) -> Self {
+        Point::new(PosX::new(p.x.into_inner().0), PosY::new(p.y.into_inner().0))
+    }
+}
+
+impl<T, Unit> Size<T, Unit> {
+    /// Wraps both dimensions in `OrderedFloat`
+    pub fn into_ordered(self) -> Size<OrderedFloat<T>, Unit> {
+        Size::new(
+            Width::new(OrderedFloat(self.width.into_inner())),
+            Height::new(OrderedFloat(self.height.into_inner())),
+        )
+    }
+
+    /// Unwraps both dimensions from `OrderedFloat`
+    pub fn from_ordered(s: Size<OrderedFloat<T>, Unit>) -> Self {
+        Size::new(Width::new(s.width.into_inner().0), Height::new(s.height.into_inner().0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::BTreeMap;
+
+    struct Grid;
+
+    #[test]
+    fn ordered_point_as_map_key() {
+        let mut map: BTreeMap<Point<OrderedFloat<f64>, Grid>, &str> = BTreeMap::new();
+        let a: Point<f64, Grid> = Point::new(PosX::new(1.5), PosY::new(2.0));
+        let b: Point<f64, Grid> = Point::new(PosX::new(-3.0), PosY::new(0.5));
+        map.insert(a.into_ordered(), "a");
+        map.insert(b.into_ordered(), "b");
+
+        assert_eq!(map.get(&a.into_ordered()), Some(&"a"));
+        let keys: Vec<_> = map.keys().map(|k| Point::from_ordered(*k)).collect();
+        assert_eq!(keys, vec![b, a]);
+    }
+}
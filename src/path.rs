@@ -0,0 +1,114 @@
+use std::fmt;
+use std::ops::Sub;
+
+use num_traits::Float;
+
+use oned::*;
+use twod::*;
+
+/// A polyline through a sequence of points, joined by straight lines
+pub struct Path<T, Unit> {
+    pub points: Vec<Point<T, Unit>>,
+}
+
+impl<T, Unit> Path<T, Unit> {
+    pub fn new(points: Vec<Point<T, Unit>>) -> Self {
+        Self { points }
+    }
+
+    /// Returns the smallest rect containing every vertex, or `None` for an empty path
+    pub fn bounding_box(&self) -> Option<Rect<T, Unit>>
+    where
+        T: Clone + PartialOrd + Sub<T, Output = T>,
+    {
+        Rect::bounding(self.points.iter().cloned())
+    }
+}
+
+impl<T: Float, Unit> Path<T, Unit> {
+    /// Returns the total length of all the lines in the path
+    pub fn length(&self) -> Length<T, Unit> {
+        let length = self.points
+            .windows(2)
+            .fold(T::zero(), |sum, pair| sum + pair[0].distance_to(&pair[1]).get());
+        Length::new(length)
+    }
+
+    /// Returns the point reached after travelling `distance` along the path from its first vertex.
+    ///
+    /// Returns `None` if the distance is negative or longer than the path.
+    pub fn point_at_distance(&self, distance: Length<T, Unit>) -> Option<Point<T, Unit>> {
+        let mut remaining = distance.get();
+        if remaining < T::zero() {
+            return None;
+        }
+
+        for pair in self.points.windows(2) {
+            let step = pair[0].distance_to(&pair[1]).get();
+            if remaining <= step {
+                let t = if step > T::zero() { remaining / step } else { T::zero() };
+                return Some(pair[0].lerp(&pair[1], t));
+            }
+            remaining = remaining - step;
+        }
+
+        match self.points.first() {
+            Some(p) if self.points.len() == 1 && remaining == T::zero() => Some(*p),
+            _ => None,
+        }
+    }
+}
+
+impl<T: Clone, Unit> Clone for Path<T, Unit> {
+    fn clone(&self) -> Self {
+        Path {
+            points: self.points.clone(),
+        }
+    }
+}
+
+impl<T: PartialEq, Unit> PartialEq for Path<T, Unit> {
+    fn eq(&self, other: &Self) -> bool {
+        self.points == other.points
+    }
+}
+
+impl<T: Eq, Unit> Eq for Path<T, Unit> {}
+
+impl<T: fmt::Debug, Unit> fmt::Debug for Path<T, Unit> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Path {{ points = {:?} }}", self.points)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    pub use super::*;
+    use test_util::*;
+
+    fn l_shape() -> Path<f64, Pixel> {
+        Path::new(vec![point(0.0, 0.0), point(30.0, 0.0), point(30.0, 40.0)])
+    }
+
+    #[test]
+    fn length() {
+        assert_eq!(l_shape().length(), Length::new(70.0));
+    }
+
+    #[test]
+    fn point_at_distance() {
+        let path = l_shape();
+        assert_eq!(path.point_at_distance(Length::new(35.0)), Some(point(30.0, 5.0)));
+        assert_eq!(path.point_at_distance(Length::new(0.0)), Some(point(0.0, 0.0)));
+        assert_eq!(path.point_at_distance(Length::new(70.0)), Some(point(30.0, 40.0)));
+        assert_eq!(path.point_at_distance(Length::new(71.0)), None);
+        assert_eq!(path.point_at_distance(Length::new(-1.0)), None);
+    }
+
+    #[test]
+    fn bounding_box() {
+        let expected = Rect::new(point(0.0, 0.0), Size::new(Width::new(30.0), Height::new(40.0)));
+        assert_eq!(l_shape().bounding_box(), Some(expected));
+        assert_eq!(Path::<f64, Pixel>::new(vec![]).bounding_box(), None);
+    }
+}
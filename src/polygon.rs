@@ -0,0 +1,208 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use num_traits::Num;
+
+use twod::*;
+use segment::*;
+
+/// A closed polygon, with an implicit edge from the last vertex back to the first
+pub struct Polygon<T, Unit> {
+    pub vertices: Vec<Point<T, Unit>>,
+}
+
+impl<T, Unit> Polygon<T, Unit> {
+    pub fn new(vertices: Vec<Point<T, Unit>>) -> Self {
+        Self { vertices }
+    }
+
+    /// Returns the convex hull of `points` with its vertices in counter-clockwise order.
+    ///
+    /// This uses Andrew's monotone chain algorithm. Duplicate points and points lying
+    /// on a straight part of the hull are not included as vertices.
+    pub fn convex_hull<I: IntoIterator<Item = Point<T, Unit>>>(points: I) -> Self
+    where
+        T: Clone + Num + PartialOrd,
+    {
+        let mut points: Vec<_> = points.into_iter().collect();
+        points.sort_by(|a, b| {
+            a.x.partial_cmp(&b.x)
+                .and_then(|o| a.y.partial_cmp(&b.y).map(|p| o.then(p)))
+                .unwrap_or(Ordering::Equal)
+        });
+        points.dedup();
+
+        if points.len() < 3 {
+            return Polygon::new(points);
+        }
+
+        let mut hull = half_hull(points.iter());
+        hull.extend(half_hull(points.iter().rev()));
+        Polygon::new(hull)
+    }
+
+    /// Iterates over the edges, ending with the one from the last vertex back to the first.
+    ///
+    /// A polygon with fewer than two vertices has no edges.
+    pub fn edges<'a>(&'a self) -> impl Iterator<Item = Segment<T, Unit>> + 'a
+    where
+        T: Clone,
+    {
+        let n = self.vertices.len();
+        let count = if n < 2 { 0 } else { n };
+        (0..count).map(move |i| {
+            Segment::new(self.vertices[i].clone(), self.vertices[(i + 1) % n].clone())
+        })
+    }
+
+    /// Returns the area computed with the shoelace formula.
+    ///
+    /// It is positive when the vertices run counter-clockwise in a coordinate system
+    /// where y points up, and negative when they run clockwise.
+    pub fn signed_area(&self) -> T
+    where
+        T: Clone + Num,
+    {
+        let n = self.vertices.len();
+        let twice_area = (0..n).fold(T::zero(), |sum, i| {
+            let (a, b) = (&self.vertices[i], &self.vertices[(i + 1) % n]);
+            sum + a.x.get() * b.y.get() - b.x.get() * a.y.get()
+        });
+        twice_area / (T::one() + T::one())
+    }
+
+    /// Returns true if the vertices run clockwise, see `signed_area` for the orientation
+    pub fn is_clockwise(&self) -> bool
+    where
+        T: Clone + Num + PartialOrd,
+    {
+        self.signed_area() < T::zero()
+    }
+
+    /// Reverses the order of the vertices if they run clockwise
+    pub fn ensure_ccw(&mut self)
+    where
+        T: Clone + Num + PartialOrd,
+    {
+        if self.is_clockwise() {
+            self.vertices.reverse();
+        }
+    }
+}
+
+/// Positive if `o`, `a` and `b` make a counter-clockwise turn, negative if clockwise
+fn turn<T: Clone + Num, Unit>(o: &Point<T, Unit>, a: &Point<T, Unit>, b: &Point<T, Unit>) -> T {
+    let (oa, ob) = (a.clone() - o.clone(), b.clone() - o.clone());
+    oa.width.get() * ob.height.get() - oa.height.get() * ob.width.get()
+}
+
+/// One half of the monotone chain, without its last point
+fn half_hull<'a, T, Unit, I>(points: I) -> Vec<Point<T, Unit>>
+where
+    T: 'a + Clone + Num + PartialOrd,
+    Unit: 'a,
+    I: Iterator<Item = &'a Point<T, Unit>>,
+{
+    let mut hull: Vec<Point<T, Unit>> = Vec::new();
+    for p in points {
+        while hull.len() >= 2 && turn(&hull[hull.len() - 2], &hull[hull.len() - 1], p) <= T::zero() {
+            hull.pop();
+        }
+        hull.push(p.clone());
+    }
+    hull.pop();
+    hull
+}
+
+impl<T: Clone, Unit> Clone for Polygon<T, Unit> {
+    fn clone(&self) -> Self {
+        Polygon {
+            vertices: self.vertices.clone(),
+        }
+    }
+}
+
+impl<T: PartialEq, Unit> PartialEq for Polygon<T, Unit> {
+    fn eq(&self, other: &Self) -> bool {
+        self.vertices == other.vertices
+    }
+}
+
+impl<T: Eq, Unit> Eq for Polygon<T, Unit> {}
+
+impl<T: fmt::Debug, Unit> fmt::Debug for Polygon<T, Unit> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Polygon {{ vertices = {:?} }}", self.vertices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    pub use super::*;
+    use test_util::*;
+
+    fn ccw_square() -> Polygon<f64, Pixel> {
+        Polygon::new(vec![point(0.0, 0.0), point(2.0, 0.0), point(2.0, 2.0), point(0.0, 2.0)])
+    }
+
+    #[test]
+    fn winding() {
+        let ccw = ccw_square();
+        assert_eq!(ccw.signed_area(), 4.0);
+        assert!(!ccw.is_clockwise());
+
+        let mut cw = ccw_square();
+        cw.vertices.reverse();
+        assert_eq!(cw.signed_area(), -4.0);
+        assert!(cw.is_clockwise());
+    }
+
+    #[test]
+    fn ensure_ccw() {
+        let mut cw = ccw_square();
+        cw.vertices.reverse();
+        cw.ensure_ccw();
+        assert!(!cw.is_clockwise());
+
+        let mut ccw = ccw_square();
+        ccw.ensure_ccw();
+        assert_eq!(ccw, ccw_square());
+    }
+
+    #[test]
+    fn convex_hull() {
+        let points = vec![
+            point(2.0, 2.0),
+            point(4.0, 4.0),
+            point(0.0, 0.0),
+            point(2.0, 0.0),
+            point(4.0, 0.0),
+            point(1.0, 3.0),
+            point(0.0, 4.0),
+            point(4.0, 4.0),
+            point(0.0, 2.0),
+        ];
+        let hull = Polygon::convex_hull(points);
+
+        assert_eq!(
+            hull,
+            Polygon::new(vec![point(0.0, 0.0), point(4.0, 0.0), point(4.0, 4.0), point(0.0, 4.0)])
+        );
+        assert!(!hull.is_clockwise());
+    }
+
+    #[test]
+    fn edges() {
+        let triangle = Polygon::new(vec![point(0.0, 0.0), point(4.0, 0.0), point(0.0, 3.0)]);
+        assert_eq!(
+            triangle.edges().collect::<Vec<_>>(),
+            vec![
+                Segment::new(point(0.0, 0.0), point(4.0, 0.0)),
+                Segment::new(point(4.0, 0.0), point(0.0, 3.0)),
+                Segment::new(point(0.0, 3.0), point(0.0, 0.0)),
+            ]
+        );
+
+        assert_eq!(Polygon::new(vec![point(1.0, 1.0)]).edges().count(), 0);
+    }
+}
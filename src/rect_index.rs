@@ -0,0 +1,80 @@
+use std::ops::Add;
+
+use twod::*;
+
+/// A collection of rects with attached values, supporting queries for the rects in an area.
+///
+/// The entries are kept sorted by their left edge, so a query can stop as soon as it reaches
+/// entries that start to the right of the area. The rects are expected to have non-negative sizes.
+pub struct RectIndex<T, Unit, V> {
+    entries: Vec<(Rect<T, Unit>, V)>,
+}
+
+impl<T, Unit, V> RectIndex<T, Unit, V> {
+    pub fn new() -> Self {
+        RectIndex { entries: Vec::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Adds a rect with its value
+    pub fn insert(&mut self, rect: Rect<T, Unit>, value: V)
+    where
+        T: PartialOrd,
+    {
+        let index = self.entries.partition_point(|(r, _)| r.origin.x <= rect.origin.x);
+        self.entries.insert(index, (rect, value));
+    }
+
+    /// Iterates over the values of all rects that intersect `area`, including ones that only touch it
+    pub fn query<'a>(&'a self, area: &Rect<T, Unit>) -> impl Iterator<Item = &'a V> + 'a
+    where
+        T: Clone + PartialOrd + Add<T, Output = T>,
+    {
+        let (start, end) = (area.origin.clone(), area.corner());
+        let right = end.x.clone();
+        self.entries
+            .iter()
+            .take_while(move |(r, _)| r.origin.x <= right)
+            .filter(move |(r, _)| {
+                let corner = r.corner();
+                corner.x >= start.x && r.origin.y <= end.y && corner.y >= start.y
+            })
+            .map(|(_, value)| value)
+    }
+}
+
+impl<T, Unit, V> Default for RectIndex<T, Unit, V> {
+    fn default() -> Self {
+        RectIndex::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_util::*;
+
+    #[test]
+    fn query() {
+        let mut index = RectIndex::new();
+        index.insert(rect(50.0, 0.0, 10.0, 10.0), "far right");
+        index.insert(rect(0.0, 0.0, 10.0, 10.0), "top left");
+        index.insert(rect(5.0, 30.0, 10.0, 10.0), "below");
+        index.insert(rect(8.0, 8.0, 10.0, 10.0), "overlapping");
+        index.insert(rect(20.0, 0.0, 10.0, 10.0), "touching");
+        assert_eq!(index.len(), 5);
+
+        let mut found: Vec<_> = index.query(&rect(5.0, 5.0, 15.0, 10.0)).cloned().collect();
+        found.sort();
+        assert_eq!(found, vec!["overlapping", "top left", "touching"]);
+
+        assert_eq!(index.query(&rect(100.0, 100.0, 1.0, 1.0)).count(), 0);
+    }
+}
@@ -0,0 +1,104 @@
+use std::fmt;
+
+use num_traits::Float;
+
+use oned::*;
+use twod::*;
+
+/// A rectangle with all four corners rounded by the same radius
+pub struct RoundedRect<T, Unit> {
+    rect: Rect<T, Unit>,
+    radius: Length<T, Unit>,
+}
+
+impl<T: Float, Unit> RoundedRect<T, Unit> {
+    /// Creates a rounded rect, clamping `radius` between zero and half the smaller side of `rect`
+    pub fn new(rect: Rect<T, Unit>, radius: Length<T, Unit>) -> Self {
+        let two = T::one() + T::one();
+        let max_radius = rect.size.width.get().min(rect.size.height.get()) / two;
+        let radius = Length::new(radius.get().min(max_radius).max(T::zero()));
+        RoundedRect { rect, radius }
+    }
+
+    /// Returns the rect with its corners not rounded
+    pub fn rect(&self) -> Rect<T, Unit> {
+        self.rect
+    }
+
+    /// Returns the corner radius, after clamping
+    pub fn radius(&self) -> Length<T, Unit> {
+        self.radius
+    }
+
+    /// Returns true if `p` lies inside the rounded rect or on its boundary
+    pub fn contains(&self, p: &Point<T, Unit>) -> bool {
+        self.signed_distance(p) <= Length::new(T::zero())
+    }
+
+    /// Returns the signed distance from the edge of the rounded rect to `p`,
+    /// negative inside and positive outside
+    pub fn signed_distance(&self, p: &Point<T, Unit>) -> Length<T, Unit> {
+        let r = self.radius.get();
+        let inner = self.rect.inflate(Width::new(-r), Height::new(-r));
+        inner.signed_distance(p) - self.radius
+    }
+}
+
+impl<T: Copy, Unit> Copy for RoundedRect<T, Unit> {}
+
+impl<T: Clone, Unit> Clone for RoundedRect<T, Unit> {
+    fn clone(&self) -> Self {
+        RoundedRect {
+            rect: self.rect.clone(),
+            radius: self.radius.clone(),
+        }
+    }
+}
+
+impl<T: PartialEq, Unit> PartialEq for RoundedRect<T, Unit> {
+    fn eq(&self, other: &Self) -> bool {
+        self.rect == other.rect && self.radius == other.radius
+    }
+}
+
+impl<T: fmt::Debug, Unit> fmt::Debug for RoundedRect<T, Unit> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RoundedRect {{ rect = {:?}, radius = {:?} }}", self.rect, self.radius)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_util::*;
+
+    fn rounded(width: f64, height: f64, radius: f64) -> RoundedRect<f64, Pixel> {
+        RoundedRect::new(rect(0.0, 0.0, width, height), Length::new(radius))
+    }
+
+    #[test]
+    fn contains() {
+        let r = rounded(100.0, 50.0, 10.0);
+        assert!(!r.contains(&point(1.0, 1.0)));
+        assert!(r.rect().contains(&point(1.0, 1.0)));
+        assert!(r.contains(&point(50.0, 0.0)));
+        assert!(r.contains(&point(0.0, 25.0)));
+        assert!(r.contains(&point(5.0, 5.0)));
+        assert!(!r.contains(&point(101.0, 25.0)));
+    }
+
+    #[test]
+    fn signed_distance() {
+        let r = rounded(100.0, 50.0, 10.0);
+        assert_eq!(r.signed_distance(&point(50.0, 20.0)), Length::new(-20.0));
+        assert_eq!(r.signed_distance(&point(100.0, 25.0)), Length::new(0.0));
+        assert_eq!(r.signed_distance(&point(4.0, 2.0)), Length::new(0.0));
+        assert_eq!(r.signed_distance(&point(-2.0, -6.0)), Length::new(10.0));
+    }
+
+    #[test]
+    fn radius_is_clamped() {
+        assert_eq!(rounded(100.0, 50.0, 40.0).radius(), Length::new(25.0));
+        assert_eq!(rounded(100.0, 50.0, -5.0).radius(), Length::new(0.0));
+    }
+}
@@ -0,0 +1,57 @@
+use std::ops::{Add, Sub, Mul, Div};
+
+/// The arithmetic most geometric operations need from a scalar type.
+///
+/// This is implemented for every type that supports these operations,
+/// so it can be used as a shorthand bound in generic code.
+pub trait Scalar:
+    Clone
+    + PartialOrd
+    + Add<Self, Output = Self>
+    + Sub<Self, Output = Self>
+    + Mul<Self, Output = Self>
+    + Div<Self, Output = Self>
+{
+}
+
+impl<T> Scalar for T where
+    T: Clone
+        + PartialOrd
+        + Add<T, Output = T>
+        + Sub<T, Output = T>
+        + Mul<T, Output = T>
+        + Div<T, Output = T>
+{
+}
+
+#[cfg(test)]
+mod tests {
+    pub use super::*;
+    use oned::*;
+    use twod::*;
+
+    struct Pixel;
+
+    fn center<T: Scalar, Unit>(r: &Rect<T, Unit>, two: T) -> Point<T, Unit> {
+        let corner = r.corner();
+        Point::new(
+            PosX::new((r.origin.x.get() + corner.x.get()) / two.clone()),
+            PosY::new((r.origin.y.get() + corner.y.get()) / two),
+        )
+    }
+
+    #[test]
+    fn generic_over_scalar() {
+        let r: Rect<i32, Pixel> = Rect::new(
+            Point::new(PosX::new(2), PosY::new(4)),
+            Size::new(Width::new(10), Height::new(6)),
+        );
+        assert_eq!(center(&r, 2), Point::new(PosX::new(7), PosY::new(7)));
+
+        let r: Rect<f64, Pixel> = Rect::new(
+            Point::new(PosX::new(0.0), PosY::new(0.0)),
+            Size::new(Width::new(5.0), Height::new(3.0)),
+        );
+        assert_eq!(center(&r, 2.0), Point::new(PosX::new(2.5), PosY::new(1.5)));
+    }
+}
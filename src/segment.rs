@@ -0,0 +1,233 @@
+use std::fmt;
+
+use num_traits::{Float, Num};
+
+use oned::*;
+use twod::*;
+
+/// A straight line segment between two points
+pub struct Segment<T, Unit> {
+    pub start: Point<T, Unit>,
+    pub end: Point<T, Unit>,
+}
+
+impl<T, Unit> Segment<T, Unit> {
+    pub fn new(start: Point<T, Unit>, end: Point<T, Unit>) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns the point on the segment closest to `p`
+    pub fn project(&self, p: &Point<T, Unit>) -> Point<T, Unit>
+    where
+        T: Clone + Num + PartialOrd,
+    {
+        let d = self.end.clone() - self.start.clone();
+        let v = p.clone() - self.start.clone();
+
+        let length_squared = d.width.get() * d.width.get() + d.height.get() * d.height.get();
+        if length_squared == T::zero() {
+            return self.start.clone();
+        }
+
+        let t = (v.width.get() * d.width.get() + v.height.get() * d.height.get()) / length_squared;
+        let t = if t < T::zero() {
+            T::zero()
+        } else if t > T::one() {
+            T::one()
+        } else {
+            t
+        };
+
+        self.start.clone() + d * t
+    }
+
+    /// Returns the shortest distance from `p` to any point on the segment
+    pub fn distance_to(&self, p: &Point<T, Unit>) -> Length<T, Unit>
+    where
+        T: Float,
+    {
+        let offset = *p - self.project(p);
+        Length::new(offset.width.get().hypot(offset.height.get()))
+    }
+
+    /// Returns the parameters `(t, u)` at which the two segments cross,
+    /// where `t` runs from 0 at `self.start` to 1 at `self.end`, and `u` likewise along `other`.
+    /// Returns `None` if the segments are parallel or do not touch.
+    pub fn intersection_params(&self, other: &Segment<T, Unit>) -> Option<(T, T)>
+    where
+        T: Clone + Num + PartialOrd,
+    {
+        let r = self.end.clone() - self.start.clone();
+        let s = other.end.clone() - other.start.clone();
+
+        let denominator = cross(&r, &s);
+        if denominator == T::zero() {
+            return None;
+        }
+
+        let offset = other.start.clone() - self.start.clone();
+        let t = cross(&offset, &s) / denominator.clone();
+        let u = cross(&offset, &r) / denominator;
+
+        let within = |x: &T| *x >= T::zero() && *x <= T::one();
+        if within(&t) && within(&u) {
+            Some((t, u))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the point where the two segments cross, if they do
+    pub fn intersection(&self, other: &Segment<T, Unit>) -> Option<Point<T, Unit>>
+    where
+        T: Clone + Num + PartialOrd,
+    {
+        self.intersection_params(other).map(|(t, _)| {
+            self.start.clone() + (self.end.clone() - self.start.clone()) * t
+        })
+    }
+}
+
+impl<T: Copy, Unit> Copy for Segment<T, Unit> {}
+
+impl<T: Clone, Unit> Clone for Segment<T, Unit> {
+    fn clone(&self) -> Self {
+        Segment {
+            start: self.start.clone(),
+            end: self.end.clone(),
+        }
+    }
+}
+
+impl<T: PartialEq, Unit> PartialEq for Segment<T, Unit> {
+    fn eq(&self, other: &Self) -> bool {
+        self.start == other.start && self.end == other.end
+    }
+}
+
+impl<T: Eq, Unit> Eq for Segment<T, Unit> {}
+
+impl<T: fmt::Debug, Unit> fmt::Debug for Segment<T, Unit> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Segment {{ start = {:?}, end = {:?} }}", self.start, self.end)
+    }
+}
+
+/// An infinite line through `point` running along `direction`
+pub struct Line<T, Unit> {
+    pub point: Point<T, Unit>,
+    pub direction: Size<T, Unit>,
+}
+
+impl<T, Unit> Line<T, Unit> {
+    pub fn new(point: Point<T, Unit>, direction: Size<T, Unit>) -> Self {
+        Self { point, direction }
+    }
+
+    /// Returns the point where the two lines cross, or `None` if they are parallel
+    pub fn intersection(&self, other: &Line<T, Unit>) -> Option<Point<T, Unit>>
+    where
+        T: Clone + Num,
+    {
+        let denominator = cross(&self.direction, &other.direction);
+        if denominator == T::zero() {
+            return None;
+        }
+
+        let offset = other.point.clone() - self.point.clone();
+        let t = cross(&offset, &other.direction) / denominator;
+        Some(self.point.clone() + self.direction.clone() * t)
+    }
+}
+
+impl<T: Copy, Unit> Copy for Line<T, Unit> {}
+
+impl<T: Clone, Unit> Clone for Line<T, Unit> {
+    fn clone(&self) -> Self {
+        Line {
+            point: self.point.clone(),
+            direction: self.direction.clone(),
+        }
+    }
+}
+
+impl<T: PartialEq, Unit> PartialEq for Line<T, Unit> {
+    fn eq(&self, other: &Self) -> bool {
+        self.point == other.point && self.direction == other.direction
+    }
+}
+
+impl<T: Eq, Unit> Eq for Line<T, Unit> {}
+
+impl<T: fmt::Debug, Unit> fmt::Debug for Line<T, Unit> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Line {{ point = {:?}, direction = {:?} }}", self.point, self.direction)
+    }
+}
+
+/// The z component of the cross product of two sizes treated as vectors
+fn cross<T: Clone + Num, Unit>(a: &Size<T, Unit>, b: &Size<T, Unit>) -> T {
+    a.width.get() * b.height.get() - a.height.get() * b.width.get()
+}
+
+#[cfg(test)]
+mod tests {
+    pub use super::*;
+    use test_util::*;
+
+    #[test]
+    fn project_onto_middle() {
+        let segment = Segment::new(point(0.0, 0.0), point(10.0, 0.0));
+        assert_eq!(segment.project(&point(4.0, 3.0)), point(4.0, 0.0));
+    }
+
+    #[test]
+    fn project_beyond_endpoint() {
+        let segment = Segment::new(point(0.0, 0.0), point(10.0, 0.0));
+        assert_eq!(segment.project(&point(15.0, 3.0)), point(10.0, 0.0));
+        assert_eq!(segment.project(&point(-5.0, -1.0)), point(0.0, 0.0));
+    }
+
+    #[test]
+    fn distance_perpendicular() {
+        let segment = Segment::new(point(0.0, 0.0), point(10.0, 0.0));
+        assert_eq!(segment.distance_to(&point(4.0, 3.0)), Length::new(3.0));
+    }
+
+    #[test]
+    fn distance_off_end() {
+        let segment = Segment::new(point(0.0, 0.0), point(10.0, 0.0));
+        assert_eq!(segment.distance_to(&point(13.0, 4.0)), Length::new(5.0));
+    }
+
+    #[test]
+    fn crossing_lines() {
+        let a = Line::new(point(0.0, 0.0), size(1.0, 1.0));
+        let b = Line::new(point(0.0, 4.0), size(1.0, -1.0));
+        assert_eq!(a.intersection(&b), Some(point(2.0, 2.0)));
+    }
+
+    #[test]
+    fn parallel_lines() {
+        let a = Line::new(point(0.0, 0.0), size(2.0, 1.0));
+        let b = Line::new(point(0.0, 3.0), size(4.0, 2.0));
+        assert_eq!(a.intersection(&b), None);
+    }
+
+    #[test]
+    fn segment_intersection_params() {
+        let a = Segment::new(point(0.0, 0.0), point(4.0, 4.0));
+        let b = Segment::new(point(0.0, 3.0), point(4.0, -1.0));
+        assert_eq!(a.intersection_params(&b), Some((0.375, 0.375)));
+        assert_eq!(b.intersection_params(&a), Some((0.375, 0.375)));
+        assert_eq!(a.intersection(&b), Some(point(1.5, 1.5)));
+    }
+
+    #[test]
+    fn segments_not_touching() {
+        let a = Segment::new(point(0.0, 0.0), point(1.0, 1.0));
+        let b = Segment::new(point(0.0, 4.0), point(4.0, 0.0));
+        assert_eq!(a.intersection_params(&b), None);
+        assert_eq!(a.intersection(&b), None);
+    }
+}
@@ -0,0 +1,317 @@
+//! Reading and writing SVG `transform` attributes and simple path data.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use num_traits::Float;
+
+use oned::*;
+use twod::*;
+use path::*;
+use transform::*;
+
+/// An error returned when parsing an SVG transform list
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum SvgParseError<E> {
+    /// The input is not a list of `name(arguments)` items
+    Malformed,
+    /// A transform function other than `matrix`, `translate`, `scale` or `rotate`
+    UnknownFunction(String),
+    /// A transform function got the wrong number of arguments
+    WrongArgumentCount(String),
+    /// One of the arguments could not be parsed as a scalar
+    InvalidNumber(E),
+    /// A path command other than `M`, `L`, `H`, `V` and `Z`, or their relative forms
+    UnsupportedCommand(char),
+}
+
+impl<E: fmt::Display> fmt::Display for SvgParseError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SvgParseError::Malformed => write!(f, "expected a list of transform functions"),
+            SvgParseError::UnknownFunction(name) => write!(f, "unknown transform function '{}'", name),
+            SvgParseError::WrongArgumentCount(name) => {
+                write!(f, "wrong number of arguments to '{}'", name)
+            }
+            SvgParseError::InvalidNumber(e) => write!(f, "invalid number: {}", e),
+            SvgParseError::UnsupportedCommand(c) => write!(
+                f,
+                "unsupported path command '{}', only M, L, H, V and Z are supported",
+                c
+            ),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> Error for SvgParseError<E> {}
+
+impl<T, UnitFrom, UnitTo> MatrixTransform<T, UnitFrom, UnitTo> {
+    /// Formats the transform as an SVG `matrix(a,b,c,d,e,f)`
+    pub fn to_svg(&self) -> String
+    where
+        T: fmt::Display,
+    {
+        let coefficients: Vec<_> = self.iter().map(|c| c.to_string()).collect();
+        format!("matrix({})", coefficients.join(","))
+    }
+
+    /// Parses an SVG transform list such as `"translate(10, 20) rotate(45)"`.
+    ///
+    /// Supports the `matrix`, `translate`, `scale` and `rotate` functions.
+    /// As in SVG, the rightmost function is applied to a point first.
+    pub fn from_svg(s: &str) -> Result<Self, SvgParseError<T::Err>>
+    where
+        T: Float + FromStr,
+    {
+        let mut matrix = identity();
+        let mut rest = s.trim();
+
+        while !rest.is_empty() {
+            let open = rest.find('(').ok_or(SvgParseError::Malformed)?;
+            let close = rest.find(')').ok_or(SvgParseError::Malformed)?;
+            if close < open {
+                return Err(SvgParseError::Malformed);
+            }
+
+            let name = rest[..open].trim();
+            let arguments = rest[open + 1..close]
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|a| !a.is_empty())
+                .map(|a| a.parse().map_err(SvgParseError::InvalidNumber))
+                .collect::<Result<Vec<T>, _>>()?;
+
+            matrix = compose(&matrix, &function(name, &arguments)?);
+            rest = rest[close + 1..].trim_start_matches(|c: char| c == ',' || c.is_whitespace());
+        }
+
+        Ok(MatrixTransform::from_column_major(matrix))
+    }
+}
+
+fn identity<T: Float>() -> [T; 6] {
+    [T::one(), T::zero(), T::zero(), T::one(), T::zero(), T::zero()]
+}
+
+/// Returns the matrix that applies `inner` first and `outer` second
+fn compose<T: Float>(outer: &[T; 6], inner: &[T; 6]) -> [T; 6] {
+    let (m, n) = (outer, inner);
+    [
+        m[0] * n[0] + m[2] * n[1],
+        m[1] * n[0] + m[3] * n[1],
+        m[0] * n[2] + m[2] * n[3],
+        m[1] * n[2] + m[3] * n[3],
+        m[0] * n[4] + m[2] * n[5] + m[4],
+        m[1] * n[4] + m[3] * n[5] + m[5],
+    ]
+}
+
+/// Builds the matrix of a single SVG transform function
+fn function<T: Float, E>(name: &str, arguments: &[T]) -> Result<[T; 6], SvgParseError<E>> {
+    let (zero, one) = (T::zero(), T::one());
+    let translate = |x: T, y: T| [one, zero, zero, one, x, y];
+
+    match (name, arguments) {
+        ("matrix", &[a, b, c, d, e, f]) => Ok([a, b, c, d, e, f]),
+        ("translate", &[x]) => Ok(translate(x, zero)),
+        ("translate", &[x, y]) => Ok(translate(x, y)),
+        ("scale", &[s]) => Ok([s, zero, zero, s, zero, zero]),
+        ("scale", &[x, y]) => Ok([x, zero, zero, y, zero, zero]),
+        ("rotate", &[angle]) | ("rotate", &[angle, _, _]) => {
+            let (sin, cos) = angle.to_radians().sin_cos();
+            let rotation = [cos, sin, -sin, cos, zero, zero];
+            match arguments {
+                &[_, x, y] => Ok(compose(&compose(&translate(x, y), &rotation), &translate(-x, -y))),
+                _ => Ok(rotation),
+            }
+        }
+        ("matrix", _) | ("translate", _) | ("scale", _) | ("rotate", _) => {
+            Err(SvgParseError::WrongArgumentCount(name.to_string()))
+        }
+        _ => Err(SvgParseError::UnknownFunction(name.to_string())),
+    }
+}
+
+impl<T, Unit> Path<T, Unit> {
+    /// Parses SVG path data made only of straight lines, such as `"M0 0 L10 0 L10 10 Z"`.
+    ///
+    /// Supports the `M`, `L`, `H`, `V` and `Z` commands and their relative lowercase forms.
+    /// `Z` repeats the start of the current subpath. Since a `Path` is a single polyline,
+    /// a later `M` is joined to the previous point by a line.
+    pub fn from_svg_path_data(data: &str) -> Result<Self, SvgParseError<T::Err>>
+    where
+        T: Float + FromStr,
+    {
+        let mut tokens = tokenize(data)?.into_iter().peekable();
+        let mut points = Vec::new();
+        let origin = (T::zero(), T::zero());
+        let (mut current, mut start) = (origin, origin);
+        let mut previous = None;
+
+        loop {
+            // Extra coordinates after a command repeat it, with `M` turning into `L`
+            let command = match tokens.peek() {
+                None => break,
+                Some(&Token::Command(c)) => c,
+                Some(&Token::Number(_)) => match previous {
+                    Some('M') => 'L',
+                    Some('m') => 'l',
+                    Some(c) if c != 'Z' && c != 'z' => c,
+                    _ => return Err(SvgParseError::Malformed),
+                },
+            };
+            if let Some(&Token::Command(_)) = tokens.peek() {
+                tokens.next();
+            }
+            previous = Some(command);
+
+            let (dx, dy) = if command.is_ascii_lowercase() { current } else { origin };
+            let mut number = || match tokens.next() {
+                Some(Token::Number(n)) => n.parse::<T>().map_err(SvgParseError::InvalidNumber),
+                _ => Err(SvgParseError::WrongArgumentCount(command.to_string())),
+            };
+
+            current = match command.to_ascii_uppercase() {
+                'M' => {
+                    start = (number()? + dx, number()? + dy);
+                    start
+                }
+                'L' => (number()? + dx, number()? + dy),
+                'H' => (number()? + dx, current.1),
+                'V' => (current.0, number()? + dy),
+                'Z' => start,
+                _ => return Err(SvgParseError::UnsupportedCommand(command)),
+            };
+            points.push(Point::new(PosX::new(current.0), PosY::new(current.1)));
+        }
+
+        Ok(Path::new(points))
+    }
+}
+
+enum Token<'a> {
+    Command(char),
+    Number(&'a str),
+}
+
+/// Splits path data into commands and numbers, which may be separated by whitespace,
+/// commas, or nothing at all when the next number starts with a sign
+fn tokenize<'a, E>(data: &'a str) -> Result<Vec<Token<'a>>, SvgParseError<E>> {
+    let mut tokens = Vec::new();
+    let mut chars = data.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        if c.is_whitespace() || c == ',' {
+            continue;
+        } else if c.is_ascii_alphabetic() {
+            tokens.push(Token::Command(c));
+        } else if c.is_ascii_digit() || c == '.' || c == '-' || c == '+' {
+            let mut end = start + 1;
+            let mut previous = c;
+            while let Some(&(i, next)) = chars.peek() {
+                let exponent = next == 'e' || next == 'E';
+                let after_exponent = previous == 'e' || previous == 'E';
+                let exponent_sign = (next == '-' || next == '+') && after_exponent;
+                if !(next.is_ascii_digit() || next == '.' || exponent || exponent_sign) {
+                    break;
+                }
+                end = i + 1;
+                previous = next;
+                chars.next();
+            }
+            tokens.push(Token::Number(&data[start..end]));
+        } else {
+            return Err(SvgParseError::Malformed);
+        }
+    }
+
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_util::*;
+
+    type Matrix = MatrixTransform<f64, Pixel, Pixel>;
+
+    fn assert_near(a: Point<f64, Pixel>, b: Point<f64, Pixel>) {
+        let d = a - b;
+        assert!(d.width.get().abs() < 1e-9 && d.height.get().abs() < 1e-9, "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn matrix_round_trip() {
+        let matrix = Matrix::from_svg("matrix(2,1,-1,3,5.5,-7)").unwrap();
+        assert_eq!(matrix.to_column_major(), [2.0, 1.0, -1.0, 3.0, 5.5, -7.0]);
+        assert_eq!(matrix.to_svg(), "matrix(2,1,-1,3,5.5,-7)");
+    }
+
+    #[test]
+    fn rotate() {
+        let rotation = Matrix::from_svg("rotate(90)").unwrap();
+        assert_near(rotation.transform_point(point(1.0, 0.0)), point(0.0, 1.0));
+
+        let about = Matrix::from_svg("rotate(90 10 10)").unwrap();
+        assert_near(about.transform_point(point(20.0, 10.0)), point(10.0, 20.0));
+    }
+
+    #[test]
+    fn list_applies_rightmost_first() {
+        let matrix = Matrix::from_svg("translate(10, 20) scale(2)").unwrap();
+        assert_eq!(matrix.transform_point(point(1.0, 1.0)), point(12.0, 22.0));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Matrix::from_svg("skewX(10)").err(),
+            Some(SvgParseError::UnknownFunction("skewX".to_string()))
+        );
+        assert_eq!(
+            Matrix::from_svg("scale(1,2,3)").err(),
+            Some(SvgParseError::WrongArgumentCount("scale".to_string()))
+        );
+        assert_eq!(Matrix::from_svg("scale(1").err(), Some(SvgParseError::Malformed));
+        assert!(matches!(Matrix::from_svg("scale(x)"), Err(SvgParseError::InvalidNumber(_))));
+    }
+
+    #[test]
+    fn path_data() {
+        let path: Path<f64, Pixel> = Path::from_svg_path_data("M0 0 L10 0 L10 10 Z").unwrap();
+        assert_eq!(
+            path.points,
+            vec![point(0.0, 0.0), point(10.0, 0.0), point(10.0, 10.0), point(0.0, 0.0)]
+        );
+    }
+
+    #[test]
+    fn path_data_relative_and_implicit() {
+        let path: Path<f64, Pixel> = Path::from_svg_path_data("m5,5 10,0 v10 h-10z").unwrap();
+        assert_eq!(
+            path.points,
+            vec![
+                point(5.0, 5.0),
+                point(15.0, 5.0),
+                point(15.0, 15.0),
+                point(5.0, 15.0),
+                point(5.0, 5.0),
+            ]
+        );
+
+        let path: Path<f64, Pixel> = Path::from_svg_path_data("M1e1-2L.5 .5").unwrap();
+        assert_eq!(path.points, vec![point(10.0, -2.0), point(0.5, 0.5)]);
+    }
+
+    #[test]
+    fn path_data_errors() {
+        assert_eq!(
+            Path::<f64, Pixel>::from_svg_path_data("M0 0 C1 1 2 2 3 3").err(),
+            Some(SvgParseError::UnsupportedCommand('C'))
+        );
+        assert_eq!(
+            Path::<f64, Pixel>::from_svg_path_data("M0 0 L5").err(),
+            Some(SvgParseError::WrongArgumentCount("L".to_string()))
+        );
+    }
+}
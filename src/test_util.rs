@@ -0,0 +1,25 @@
+//! Shorthands for building geometry in tests.
+
+use oned::*;
+use twod::*;
+
+pub struct Pixel;
+
+pub fn point(x: f64, y: f64) -> Point<f64, Pixel> {
+    Point::new(PosX::new(x), PosY::new(y))
+}
+
+pub fn size(width: f64, height: f64) -> Size<f64, Pixel> {
+    Size::new(Width::new(width), Height::new(height))
+}
+
+pub fn rect(x: f64, y: f64, width: f64, height: f64) -> Rect<f64, Pixel> {
+    Rect::new(point(x, y), size(width, height))
+}
+
+pub fn int_rect(x: i32, y: i32, width: i32, height: i32) -> Rect<i32, Pixel> {
+    Rect::new(
+        Point::new(PosX::new(x), PosY::new(y)),
+        Size::new(Width::new(width), Height::new(height)),
+    )
+}
@@ -1,8 +1,12 @@
 use std::marker::PhantomData;
-use std::ops::{Add, Sub, Mul, Div};
+use std::ops::{Add, Sub, Mul, Div, Neg};
 
-use oned::*;
-use twod::*;
+use num_traits::{Float, Num, One, Signed, Zero};
+
+use crate::oned::*;
+use crate::twod::*;
+use crate::circle::*;
+use crate::layout::*;
 
 pub trait AxisAlignedTransform<T, UnitFrom> {
     type OutT;
@@ -34,28 +38,115 @@ pub trait AxisAlignedTransform<T, UnitFrom> {
             y: self.transform_position_y(p.y),
         }
     }
+
+    /// Transforms a circle, or returns `None` if the transform scales the two axes differently
+    /// and would turn the circle into an ellipse.
+    ///
+    /// Reflections are allowed, since a mirrored circle is still a circle.
+    fn transform_circle(&self, c: Circle<T, UnitFrom>) -> Option<Circle<Self::OutT, Self::OutUnit>>
+    where
+        T: Clone,
+        Self::OutT: Signed,
+    {
+        let width = self.transform_width(Width::new(c.radius.get())).into_inner().abs();
+        let height = self.transform_height(Height::new(c.radius.into_inner())).into_inner().abs();
+        if width != height {
+            return None;
+        }
+        Some(Circle::new(self.transform_point(c.center), Length::new(width)))
+    }
 }
 
+/// A transform of points, which can also rotate or skew.
+///
+/// Every axis-aligned transform is also a `Transform`, so they can be used interchangeably.
+/// Since both traits have a `transform_point`, call it through the trait name when both are
+/// in scope.
+///
+/// ```
+/// use planar::{MatrixTransform, Point, PosX, PosY, ScaleFactor};
+/// use planar::{Height, Size, Transform, Translation, Width};
+/// struct Pixel;
+///
+/// let p: Point<f64, Pixel> = Point::new(PosX::new(1.0), PosY::new(2.0));
+/// let shift = Translation::new(Size::new(Width::new(3.0), Height::new(4.0)));
+/// assert_eq!(shift.transform_point(p), Point::new(PosX::new(4.0), PosY::new(6.0)));
+///
+/// let scale: ScaleFactor<f64, f64, f64, Pixel, Pixel> = ScaleFactor::identity();
+/// assert_eq!(scale.transform_points(&[p]), vec![p]);
+///
+/// let swap: MatrixTransform<f64, Pixel, Pixel> =
+///     MatrixTransform::new([0.0, 1.0, 1.0, 0.0, 0.0, 0.0]);
+/// assert_eq!(swap.transform_point(p), Point::new(PosX::new(2.0), PosY::new(1.0)));
+/// ```
 pub trait Transform<T, UnitFrom> {
     type OutT;
     type OutUnit;
 
     fn transform_point(&self, p: Point<T, UnitFrom>) -> Point<Self::OutT, Self::OutUnit>;
-}
 
-impl<T, UnitFrom, W, UnitTo> Transform<T, UnitFrom>
-    for AxisAlignedTransform<T, UnitFrom, OutT = W, OutUnit = UnitTo> {
-    type OutT = W;
-    type OutUnit = UnitTo;
+    /// Transforms every point in the slice, returning the results in the same order
+    fn transform_points(&self, points: &[Point<T, UnitFrom>]) -> Vec<Point<Self::OutT, Self::OutUnit>>
+    where
+        T: Clone,
+    {
+        points.iter().map(|p| self.transform_point(p.clone())).collect()
+    }
 
-    fn transform_point(&self, p: Point<T, UnitFrom>) -> Point<Self::OutT, Self::OutUnit> {
-        Point {
-            x: self.transform_position_x(p.x),
-            y: self.transform_position_y(p.y),
+    /// Transforms every point in the slice in place, for transforms that keep the scalar and unit
+    fn transform_points_in_place(&self, points: &mut [Point<T, UnitFrom>])
+    where
+        Self: Transform<T, UnitFrom, OutT = T, OutUnit = UnitFrom>,
+        T: Clone,
+    {
+        for p in points.iter_mut() {
+            *p = self.transform_point(p.clone());
         }
     }
 }
 
+// This can not be a blanket impl over all `AxisAlignedTransform`s, because it would overlap with
+// the impls for the matrix transforms: a downstream crate could implement `AxisAlignedTransform`
+// for them with its own scalar type. So each axis-aligned transform gets its own impl instead.
+macro_rules! impl_transform_for_axis_aligned {
+    ([$($generics:tt)*] $t:ty) => {
+        impl<$($generics)*> Transform<T, UnitFrom> for $t
+        where
+            $t: AxisAlignedTransform<T, UnitFrom>,
+        {
+            type OutT = <$t as AxisAlignedTransform<T, UnitFrom>>::OutT;
+            type OutUnit = <$t as AxisAlignedTransform<T, UnitFrom>>::OutUnit;
+
+            fn transform_point(&self, p: Point<T, UnitFrom>) -> Point<Self::OutT, Self::OutUnit> {
+                Point {
+                    x: self.transform_position_x(p.x),
+                    y: self.transform_position_y(p.y),
+                }
+            }
+        }
+    };
+}
+
+impl_transform_for_axis_aligned!(
+    [T, UnitFrom, W, UnitTo] dyn AxisAlignedTransform<T, UnitFrom, OutT = W, OutUnit = UnitTo>
+);
+impl_transform_for_axis_aligned!(
+    [T, UnitFrom, W, UnitTo] IdentityTransform<T, UnitFrom, W, UnitTo>
+);
+impl_transform_for_axis_aligned!([T, UnitFrom] Translation<T, UnitFrom>);
+impl_transform_for_axis_aligned!([T, UnitFrom] FlipX<T, UnitFrom>);
+impl_transform_for_axis_aligned!([T, UnitFrom] FlipY<T, UnitFrom>);
+impl_transform_for_axis_aligned!(
+    [T: Mul<V, Output = W>, V: Clone, W, UnitFrom, UnitTo] ScaleFactor<T, V, W, UnitFrom, UnitTo>
+);
+impl_transform_for_axis_aligned!(
+    [T: Mul<V, Output = W>, V: Clone, W, UnitFrom, UnitTo]
+    AnisotropicScale<T, V, W, UnitFrom, UnitTo>
+);
+impl_transform_for_axis_aligned!(
+    [T, V, W, Y, Z, UnitFrom, UnitTo] AxisAlignedMatrixTransform<T, V, W, Y, Z, UnitFrom, UnitTo>
+);
+
 pub struct IdentityTransform<T, UnitFrom, W, UnitTo>(PhantomData<(T, UnitFrom, W, UnitTo)>);
 
 impl<T: Into<W>, UnitFrom, W, UnitTo> AxisAlignedTransform<T, UnitFrom>
@@ -80,6 +171,20 @@ impl<T: Into<W>, UnitFrom, W, UnitTo> AxisAlignedTransform<T, UnitFrom>
 
 pub struct Translation<T, Unit>(Size<T, Unit>);
 
+impl<T, Unit> Translation<T, Unit> {
+    /// Creates a translation that moves everything by `offset`
+    pub fn new(offset: Size<T, Unit>) -> Self {
+        Translation(offset)
+    }
+}
+
+impl<T: Zero, Unit> Translation<T, Unit> {
+    /// Returns a translation by zero, which leaves everything in place
+    pub fn identity() -> Self {
+        Translation(Size::new(Width::new(T::zero()), Height::new(T::zero())))
+    }
+}
+
 impl<T: Clone + Add<T, Output = T>, Unit> AxisAlignedTransform<T, Unit> for Translation<T, Unit> {
     type OutT = T;
     type OutUnit = Unit;
@@ -99,11 +204,127 @@ impl<T: Clone + Add<T, Output = T>, Unit> AxisAlignedTransform<T, Unit> for Tran
     }
 }
 
+/// A reflection about the vertical line through a given x coordinate
+pub struct FlipX<T, Unit>(PosX<T, Unit>);
+
+impl<T, Unit> FlipX<T, Unit> {
+    pub fn new(axis: PosX<T, Unit>) -> Self {
+        FlipX(axis)
+    }
+}
+
+impl<T: Zero, Unit> FlipX<T, Unit> {
+    /// Returns a reflection about the y axis, negating every x coordinate
+    pub fn about_origin() -> Self {
+        FlipX(PosX::new(T::zero()))
+    }
+}
+
+/// Widths are negated, but rects are normalized so that their size stays non-negative
+impl<T, Unit> AxisAlignedTransform<T, Unit> for FlipX<T, Unit>
+where
+    T: Clone + Add<T, Output = T> + Sub<T, Output = T> + Neg<Output = T>,
+{
+    type OutT = T;
+    type OutUnit = Unit;
+
+    fn transform_position_x(&self, x: PosX<T, Unit>) -> PosX<T, Unit> {
+        self.0.clone() + (self.0.clone() - x)
+    }
+    fn transform_position_y(&self, y: PosY<T, Unit>) -> PosY<T, Unit> {
+        y
+    }
+
+    fn transform_width(&self, w: Width<T, Unit>) -> Width<T, Unit> {
+        -w
+    }
+    fn transform_height(&self, h: Height<T, Unit>) -> Height<T, Unit> {
+        h
+    }
+
+    fn transform_rect(&self, r: Rect<T, Unit>) -> Rect<T, Unit> {
+        let right = r.corner().x;
+        Rect::new(Point::new(self.transform_position_x(right), r.origin.y), r.size)
+    }
+}
+
+/// A reflection about the horizontal line through a given y coordinate
+pub struct FlipY<T, Unit>(PosY<T, Unit>);
+
+impl<T, Unit> FlipY<T, Unit> {
+    pub fn new(axis: PosY<T, Unit>) -> Self {
+        FlipY(axis)
+    }
+}
+
+impl<T: Zero, Unit> FlipY<T, Unit> {
+    /// Returns a reflection about the x axis, negating every y coordinate
+    pub fn about_origin() -> Self {
+        FlipY(PosY::new(T::zero()))
+    }
+}
+
+/// Heights are negated, but rects are normalized so that their size stays non-negative
+impl<T, Unit> AxisAlignedTransform<T, Unit> for FlipY<T, Unit>
+where
+    T: Clone + Add<T, Output = T> + Sub<T, Output = T> + Neg<Output = T>,
+{
+    type OutT = T;
+    type OutUnit = Unit;
+
+    fn transform_position_x(&self, x: PosX<T, Unit>) -> PosX<T, Unit> {
+        x
+    }
+    fn transform_position_y(&self, y: PosY<T, Unit>) -> PosY<T, Unit> {
+        self.0.clone() + (self.0.clone() - y)
+    }
+
+    fn transform_width(&self, w: Width<T, Unit>) -> Width<T, Unit> {
+        w
+    }
+    fn transform_height(&self, h: Height<T, Unit>) -> Height<T, Unit> {
+        -h
+    }
+
+    fn transform_rect(&self, r: Rect<T, Unit>) -> Rect<T, Unit> {
+        let bottom = r.corner().y;
+        Rect::new(Point::new(r.origin.x, self.transform_position_y(bottom)), r.size)
+    }
+}
+
 pub struct ScaleFactor<T: Mul<V, Output = W>, V: Clone, W, UnitFrom, UnitTo>(
     V,
     PhantomData<(T, UnitFrom, W, UnitTo)>
 );
 
+impl<T: Mul<V, Output = W>, V: Clone, W, UnitFrom, UnitTo> ScaleFactor<T, V, W, UnitFrom, UnitTo> {
+    /// Returns the scale factor that maps `from` onto `to`,
+    /// for example from a reference length measured in both units
+    pub fn from_lengths(from: Width<T, UnitFrom>, to: Width<W, UnitTo>) -> Self
+    where
+        W: Div<T, Output = V>,
+    {
+        ScaleFactor(to.into_inner() / from.into_inner(), PhantomData {})
+    }
+
+    /// Returns the same scale as a matrix with no translation, ready to be combined with one
+    pub fn to_axis_aligned_matrix(
+        &self,
+    ) -> AxisAlignedMatrixTransform<T, V, W, W, W, UnitFrom, UnitTo>
+    where
+        W: Zero,
+    {
+        AxisAlignedMatrixTransform::new(self.0.clone(), self.0.clone(), W::zero(), W::zero())
+    }
+}
+
+impl<T: Mul<V, Output = W>, V: Clone + One, W, Unit> ScaleFactor<T, V, W, Unit, Unit> {
+    /// Returns a scale factor of one between a unit and itself
+    pub fn identity() -> Self {
+        ScaleFactor(V::one(), PhantomData {})
+    }
+}
+
 impl<T: Clone + Mul<V, Output = W>, V: Clone, UnitFrom, W, UnitTo> AxisAlignedTransform<T, UnitFrom>
     for ScaleFactor<T, V, W, UnitFrom, UnitTo> {
     type OutT = W;
@@ -124,17 +345,122 @@ impl<T: Clone + Mul<V, Output = W>, V: Clone, UnitFrom, W, UnitTo> AxisAlignedTr
     }
 }
 
+/// A scale with separate factors for the horizontal and vertical axes
+pub struct AnisotropicScale<T: Mul<V, Output = W>, V: Clone, W, UnitFrom, UnitTo>(
+    V,
+    V,
+    PhantomData<(T, UnitFrom, W, UnitTo)>
+);
+
+impl<T: Mul<V, Output = W>, V: Clone, W, UnitFrom, UnitTo> AnisotropicScale<T, V, W, UnitFrom, UnitTo> {
+    pub fn new(scale_x: V, scale_y: V) -> Self {
+        AnisotropicScale(scale_x, scale_y, PhantomData {})
+    }
+}
+
+impl<T: Mul<V, Output = W>, V: Clone, UnitFrom, W, UnitTo> AxisAlignedTransform<T, UnitFrom>
+    for AnisotropicScale<T, V, W, UnitFrom, UnitTo> {
+    type OutT = W;
+    type OutUnit = UnitTo;
+
+    fn transform_position_x(&self, x: PosX<T, UnitFrom>) -> PosX<W, UnitTo> {
+        PosX::new(x.into_inner() * self.0.clone())
+    }
+    fn transform_position_y(&self, y: PosY<T, UnitFrom>) -> PosY<W, UnitTo> {
+        PosY::new(y.into_inner() * self.1.clone())
+    }
+
+    fn transform_width(&self, w: Width<T, UnitFrom>) -> Width<W, UnitTo> {
+        Width::new(w.into_inner() * self.0.clone())
+    }
+    fn transform_height(&self, h: Height<T, UnitFrom>) -> Height<W, UnitTo> {
+        Height::new(h.into_inner() * self.1.clone())
+    }
+}
+
 pub struct MatrixTransform<T, UnitFrom, UnitTo>([T; 6], PhantomData<(UnitFrom, UnitTo)>);
 
 impl<T, UnitFrom, UnitTo> MatrixTransform<T, UnitFrom, UnitTo> {
-    pub fn new(data: [T; 6]) -> Self
-    where T: Clone {
-        MatrixTransform(data.clone(), PhantomData{})
+    /// Creates a transform from its coefficients in column-major order,
+    /// the same as `from_column_major`
+    pub fn new(data: [T; 6]) -> Self {
+        MatrixTransform::from_column_major(data)
     }
 
     pub fn iter<'a>(&'a self) -> ::std::slice::Iter<'a, T> {
         self.0.iter()
     }
+
+    /// Creates a transform from the first two rows of a 3×3 matrix, stored column by column.
+    ///
+    /// The coefficients `[a, b, c, d, e, f]` map a point `(x, y)` to `(a x + c y + e, b x + d y + f)`,
+    /// the same as the SVG `matrix(a, b, c, d, e, f)`.
+    pub fn from_column_major(data: [T; 6]) -> Self {
+        MatrixTransform(data, PhantomData {})
+    }
+
+    /// Returns the affine transform that maps each of the `from` points to the matching `to` point,
+    /// or `None` if the `from` points are collinear and do not determine a unique transform
+    pub fn from_three_points(
+        from: [Point<T, UnitFrom>; 3],
+        to: [Point<T, UnitTo>; 3],
+    ) -> Option<Self>
+    where
+        T: Float,
+    {
+        let [p0, p1, p2] = from;
+        let [q0, q1, q2] = to;
+        let (u, v) = (p1 - p0, p2 - p0);
+        let (s, t) = (q1 - q0, q2 - q0);
+
+        let det = u.width.get() * v.height.get() - v.width.get() * u.height.get();
+        if det == T::zero() {
+            return None;
+        }
+
+        // The linear part maps `u` to `s` and `v` to `t`
+        let a = (s.width.get() * v.height.get() - t.width.get() * u.height.get()) / det;
+        let b = (s.height.get() * v.height.get() - t.height.get() * u.height.get()) / det;
+        let c = (t.width.get() * u.width.get() - s.width.get() * v.width.get()) / det;
+        let d = (t.height.get() * u.width.get() - s.height.get() * v.width.get()) / det;
+
+        let (x, y) = (p0.x.get(), p0.y.get());
+        let e = q0.x.get() - (a * x + c * y);
+        let f = q0.y.get() - (b * x + d * y);
+
+        Some(MatrixTransform::from_column_major([a, b, c, d, e, f]))
+    }
+
+    /// Returns the coefficients in the order accepted by `from_column_major`
+    pub fn to_column_major(&self) -> [T; 6]
+    where
+        T: Clone,
+    {
+        self.0.clone()
+    }
+
+    /// Returns true if the transform only scales (possibly flipping) and translates,
+    /// that is if both off-diagonal coefficients are within `epsilon` of zero
+    pub fn is_axis_aligned(&self, epsilon: T) -> bool
+    where
+        T: Signed + PartialOrd,
+    {
+        self.0[1].abs() <= epsilon && self.0[2].abs() <= epsilon
+    }
+
+    /// Returns the equivalent axis-aligned transform, or `None` if the matrix also rotates or shears
+    pub fn to_axis_aligned(
+        &self,
+    ) -> Option<AxisAlignedMatrixTransform<T, T, T, T, T, UnitFrom, UnitTo>>
+    where
+        T: Clone + Signed + PartialOrd,
+    {
+        if !self.is_axis_aligned(T::zero()) {
+            return None;
+        }
+        let m = &self.0;
+        Some(AxisAlignedMatrixTransform::new(m[0].clone(), m[3].clone(), m[4].clone(), m[5].clone()))
+    }
 }
 
 impl<T, UnitFrom, UnitTo> Transform<T, UnitFrom>
@@ -157,6 +483,68 @@ where
     }
 }
 
+/// A general 3×3 transform in homogeneous coordinates.
+///
+/// The coefficients are stored column by column, so a point `(x, y)` maps to
+/// `(m[0] x + m[3] y + m[6], m[1] x + m[4] y + m[7])` divided by `m[2] x + m[5] y + m[8]`.
+/// The affine `MatrixTransform` `[a, b, c, d, e, f]` is equivalent to `[a, b, 0, c, d, 0, e, f, 1]`.
+pub struct Matrix3Transform<T, UnitFrom, UnitTo>([T; 9], PhantomData<(UnitFrom, UnitTo)>);
+
+impl<T, UnitFrom, UnitTo> Matrix3Transform<T, UnitFrom, UnitTo> {
+    pub fn new(data: [T; 9]) -> Self {
+        Matrix3Transform(data, PhantomData {})
+    }
+
+    pub fn iter<'a>(&'a self) -> ::std::slice::Iter<'a, T> {
+        self.0.iter()
+    }
+}
+
+impl<T: Num, Unit> Matrix3Transform<T, Unit, Unit> {
+    pub fn identity() -> Self {
+        Matrix3Transform::new([
+            T::one(), T::zero(), T::zero(),
+            T::zero(), T::one(), T::zero(),
+            T::zero(), T::zero(), T::one(),
+        ])
+    }
+}
+
+impl<T: Clone + Num, UnitFrom, UnitTo> Transform<T, UnitFrom> for Matrix3Transform<T, UnitFrom, UnitTo> {
+    type OutT = T;
+    type OutUnit = UnitTo;
+
+    fn transform_point(&self, p: Point<T, UnitFrom>) -> Point<T, UnitTo> {
+        let m = &self.0;
+        let (x, y) = (p.x.into_inner(), p.y.into_inner());
+        let row = |i: usize| m[i].clone() * x.clone() + m[i + 3].clone() * y.clone() + m[i + 6].clone();
+        let w = row(2);
+        Point {
+            x: PosX::new(row(0) / w.clone()),
+            y: PosY::new(row(1) / w),
+        }
+    }
+}
+
+/// Composes two transforms, so that `(b * a) * p == b * (a * p)`
+impl<T: Clone + Num, UnitFrom, UnitVia, UnitTo> Mul<Matrix3Transform<T, UnitFrom, UnitVia>>
+    for Matrix3Transform<T, UnitVia, UnitTo> {
+    type Output = Matrix3Transform<T, UnitFrom, UnitTo>;
+
+    fn mul(self, other: Matrix3Transform<T, UnitFrom, UnitVia>) -> Self::Output {
+        let (a, b) = (&self.0, &other.0);
+        let element = |i: usize| {
+            let (column, row) = (i / 3, i % 3);
+            (0..3).fold(T::zero(), |sum, k| sum + a[k * 3 + row].clone() * b[column * 3 + k].clone())
+        };
+        Matrix3Transform::new([
+            element(0), element(1), element(2),
+            element(3), element(4), element(5),
+            element(6), element(7), element(8),
+        ])
+    }
+}
+
 pub struct AxisAlignedMatrixTransform<T, V, W, Y, Z, UnitFrom, UnitTo>(
     V,
     V,
@@ -226,6 +614,87 @@ impl<T, V, W, Y, Z, UnitFrom, UnitTo> AxisAlignedMatrixTransform<T, V, W, Y, Z,
     }
 }
 
+impl<T: Float, UnitFrom, UnitTo> AxisAlignedMatrixTransform<T, T, T, T, T, UnitFrom, UnitTo> {
+    /// Returns the rect that this transform maps onto `screen_rect`,
+    /// or `None` if the transform is not invertible because it has a zero scale
+    pub fn inverse_image_of_rect(
+        &self,
+        screen_rect: &Rect<T, UnitTo>,
+    ) -> Option<Rect<T, UnitFrom>> {
+        if self.0 == T::zero() || self.1 == T::zero() {
+            return None;
+        }
+        let inverse = self.inverse();
+        let a = AxisAlignedTransform::transform_point(&inverse, screen_rect.origin);
+        let b = AxisAlignedTransform::transform_point(&inverse, screen_rect.corner());
+        Some(Rect::from_min_max(a.component_min(&b), a.component_max(&b)))
+    }
+
+    /// Returns how much more `other` scales along each axis than this transform does
+    pub fn relative_scale(&self, other: &Self) -> Size<T, UnitTo> {
+        Size::new(Width::new(other.0 / self.0), Height::new(other.1 / self.1))
+    }
+
+    /// Like `from_rects`, but scales `from` according to `fit` and aligns it inside `to` by `anchor`.
+    ///
+    /// With `Fit::ScaleDown`, one `UnitFrom` is taken to be the same size as one `UnitTo`.
+    pub fn from_rects_aligned(
+        from: Rect<T, UnitFrom>,
+        to: Rect<T, UnitTo>,
+        fit: Fit,
+        anchor: Anchor,
+    ) -> Self {
+        let placed = from.size.map_unit().place_in(&to, fit, anchor);
+        AxisAlignedMatrixTransform::from_rects(from, placed)
+    }
+
+    /// Returns the transform that scales `content` as large as it fits inside `viewport`
+    /// inset by `margin` on every side, keeping its aspect ratio and centering it.
+    ///
+    /// If the margins leave no room along an axis, the content is scaled down to nothing.
+    pub fn fit_content(
+        content: &Rect<T, UnitFrom>,
+        viewport: &Rect<T, UnitTo>,
+        margin: Length<T, UnitTo>,
+    ) -> Self {
+        let mut inset = viewport.inflate(Width::new(-margin.get()), Height::new(-margin.get()));
+        if inset.size.width.get() < T::zero() {
+            inset.origin.x = viewport.origin.x + viewport.size.width / (T::one() + T::one());
+            inset.size.width = Width::new(T::zero());
+        }
+        if inset.size.height.get() < T::zero() {
+            inset.origin.y = viewport.origin.y + viewport.size.height / (T::one() + T::one());
+            inset.size.height = Height::new(T::zero());
+        }
+        AxisAlignedMatrixTransform::from_rects_aligned(*content, inset, Fit::Contain, Anchor::Center)
+    }
+
+    /// Returns the transform from normalized device coordinates, where both axes span `[-1, 1]`,
+    /// into `viewport`.
+    ///
+    /// With `flip_y`, NDC `y = 1` maps to the top (smallest y) of the viewport instead of the bottom.
+    pub fn ndc_to_viewport(viewport: &Rect<T, UnitTo>, flip_y: bool) -> Self {
+        let two = T::one() + T::one();
+        let half_width = viewport.size.width.get() / two;
+        let half_height = viewport.size.height.get() / two;
+        let scale_y = if flip_y { -half_height } else { half_height };
+
+        AxisAlignedMatrixTransform::new(
+            half_width,
+            scale_y,
+            viewport.origin.x.get() + half_width,
+            viewport.origin.y.get() + half_height,
+        )
+    }
+}
+
+impl<T, V: One, W, Y: Zero, Z, Unit> AxisAlignedMatrixTransform<T, V, W, Y, Z, Unit, Unit> {
+    /// Returns a transform with unit scale and no translation
+    pub fn identity() -> Self {
+        AxisAlignedMatrixTransform::new(V::one(), V::one(), Y::zero(), Y::zero())
+    }
+}
+
 macro_rules! impl_mul_for_transform {
     ($mac:ident) => {
         $mac!(PosX, transform_position_x);
@@ -246,7 +715,7 @@ macro_rules! impl_scale_factor_mul {
             for &'a ScaleFactor<T, V, W, UnitFrom, UnitTo> {
             type Output = $s<W, UnitTo>;
             fn mul(self, p: $s<T, UnitFrom>) -> Self::Output {
-                self.$m(p)
+                AxisAlignedTransform::$m(self, p)
             }
         }
     }
@@ -254,13 +723,27 @@ macro_rules! impl_scale_factor_mul {
 
 impl_mul_for_transform!(impl_scale_factor_mul);
 
+macro_rules! impl_anisotropic_scale_mul {
+    ($s:ident, $m:ident) => {
+        impl<'a, T: Mul<V, Output = W>, V: Clone, W, UnitFrom, UnitTo> Mul<$s<T, UnitFrom>>
+            for &'a AnisotropicScale<T, V, W, UnitFrom, UnitTo> {
+            type Output = $s<W, UnitTo>;
+            fn mul(self, p: $s<T, UnitFrom>) -> Self::Output {
+                AxisAlignedTransform::$m(self, p)
+            }
+        }
+    }
+}
+
+impl_mul_for_transform!(impl_anisotropic_scale_mul);
+
 macro_rules! impl_identity_mul {
     ($s:ident, $m:ident) => {
         impl<T: Into<W>, UnitFrom, W, UnitTo> Mul<$s<T, UnitFrom>>
         for IdentityTransform<T, UnitFrom, W, UnitTo> {
             type Output = $s<W, UnitTo>;
             fn mul(self, p: $s<T, UnitFrom>) -> Self::Output {
-                self.$m(p)
+                AxisAlignedTransform::$m(&self, p)
             }
         }
     }
@@ -274,7 +757,7 @@ macro_rules! impl_translation_mul {
         for Translation<T, Unit> {
             type Output = $s<T, Unit>;
             fn mul(self, p: $s<T, Unit>) -> Self::Output {
-                self.$m(p)
+                AxisAlignedTransform::$m(&self, p)
             }
         }
     }
@@ -294,7 +777,7 @@ where
     {
             type Output = $s<Z, UnitTo>;
             fn mul(self, p: $s<T, UnitFrom>) -> Self::Output {
-                self.$m(p)
+                AxisAlignedTransform::$m(&self, p)
             }
         }
     }
@@ -319,6 +802,156 @@ macro_rules! impl_matrix_mul {
 
 impl_matrix_mul!(Point, transform_point);
 
+impl<T: Clone + Num, UnitFrom, UnitTo> Mul<Point<T, UnitFrom>> for Matrix3Transform<T, UnitFrom, UnitTo> {
+    type Output = Point<T, UnitTo>;
+    fn mul(self, p: Point<T, UnitFrom>) -> Self::Output {
+        self.transform_point(p)
+    }
+}
+
+macro_rules! impl_transformed_by {
+    ($s:ident, $m:ident) => {
+        impl<T, Unit> $s<T, Unit> {
+            /// Applies an axis-aligned transform, for chaining from the geometry's side
+            pub fn transformed_by<Tr: AxisAlignedTransform<T, Unit> + ?Sized>(self, t: &Tr)
+                -> $s<Tr::OutT, Tr::OutUnit> {
+                AxisAlignedTransform::$m(t, self)
+            }
+        }
+    }
+}
+
+impl_transformed_by!(PosX, transform_position_x);
+impl_transformed_by!(PosY, transform_position_y);
+impl_transformed_by!(Width, transform_width);
+impl_transformed_by!(Height, transform_height);
+impl_transformed_by!(Size, transform_size);
+impl_transformed_by!(Rect, transform_rect);
+
+impl<T, Unit> Point<T, Unit> {
+    /// Applies a transform, for chaining from the point's side
+    pub fn transformed_by<Tr>(self, t: &Tr) -> Point<Tr::OutT, Tr::OutUnit>
+    where
+        Tr: Transform<T, Unit> + ?Sized,
+    {
+        t.transform_point(self)
+    }
+
+    /// Applies a transform that keeps the scalar type and unit, replacing this point with the result
+    pub fn transform_in_place<Tr>(&mut self, t: &Tr)
+    where
+        Tr: Transform<T, Unit, OutT = T, OutUnit = Unit> + ?Sized,
+        T: Clone,
+    {
+        *self = t.transform_point(self.clone());
+    }
+}
+
+/// An iterator that lazily applies a transform to each point of the inner iterator.
+///
+/// Created by `PointIteratorExt::transformed`.
+pub struct TransformedPoints<'a, I, Tr: ?Sized + 'a> {
+    inner: I,
+    transform: &'a Tr,
+}
+
+impl<'a, I, Tr, T, Unit> Iterator for TransformedPoints<'a, I, Tr>
+where
+    I: Iterator<Item = Point<T, Unit>>,
+    Tr: Transform<T, Unit> + ?Sized,
+{
+    type Item = Point<Tr::OutT, Tr::OutUnit>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|p| self.transform.transform_point(p))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// Adds `transformed` to every iterator over points
+pub trait PointIteratorExt: Iterator + Sized {
+    /// Applies `transform` to each point as it is yielded
+    fn transformed<'a, Tr: ?Sized>(self, transform: &'a Tr) -> TransformedPoints<'a, Self, Tr> {
+        TransformedPoints {
+            inner: self,
+            transform,
+        }
+    }
+}
+
+impl<T, Unit, I: Iterator<Item = Point<T, Unit>>> PointIteratorExt for I {}
+
+/// A transform that can be undone.
+///
+/// The inverse of a singular transform (one with a zero scale) is not meaningful,
+/// and computing it divides by zero.
+pub trait Invertible {
+    type Inverse;
+
+    fn inverse(&self) -> Self::Inverse;
+}
+
+impl<T: Clone + Neg<Output = T>, Unit> Invertible for Translation<T, Unit> {
+    type Inverse = Translation<T, Unit>;
+
+    fn inverse(&self) -> Self::Inverse {
+        Translation(-self.0.clone())
+    }
+}
+
+impl<T, V, W, UnitFrom, UnitTo> Invertible for ScaleFactor<T, V, W, UnitFrom, UnitTo>
+where
+    T: Mul<V, Output = W>,
+    V: Clone + One + Div<V, Output = V>,
+    W: Mul<V, Output = T>,
+{
+    type Inverse = ScaleFactor<W, V, T, UnitTo, UnitFrom>;
+
+    fn inverse(&self) -> Self::Inverse {
+        ScaleFactor(V::one() / self.0.clone(), PhantomData {})
+    }
+}
+
+impl<T, UnitFrom, UnitTo> Invertible for AxisAlignedMatrixTransform<T, T, T, T, T, UnitFrom, UnitTo>
+where
+    T: Clone + Num + Neg<Output = T>,
+{
+    type Inverse = AxisAlignedMatrixTransform<T, T, T, T, T, UnitTo, UnitFrom>;
+
+    fn inverse(&self) -> Self::Inverse {
+        AxisAlignedMatrixTransform::new(
+            T::one() / self.0.clone(),
+            T::one() / self.1.clone(),
+            -self.2.clone() / self.0.clone(),
+            -self.3.clone() / self.1.clone(),
+        )
+    }
+}
+
+impl<T, UnitFrom, UnitTo> Invertible for MatrixTransform<T, UnitFrom, UnitTo>
+where
+    T: Clone + Num + Neg<Output = T>,
+{
+    type Inverse = MatrixTransform<T, UnitTo, UnitFrom>;
+
+    fn inverse(&self) -> Self::Inverse {
+        let [a, b, c, d, e, f] = self.0.clone();
+        let det = a.clone() * d.clone() - b.clone() * c.clone();
+
+        let a2 = d / det.clone();
+        let b2 = -b / det.clone();
+        let c2 = -c / det.clone();
+        let d2 = a / det;
+        let e2 = -(a2.clone() * e.clone() + c2.clone() * f.clone());
+        let f2 = -(b2.clone() * e + d2.clone() * f);
+
+        MatrixTransform::new([a2, b2, c2, d2, e2, f2])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     pub use super::*;
@@ -352,4 +985,334 @@ mod tests {
 
         assert_eq!(&f * w, w2);
     }
+
+    fn point<U>(x: f64, y: f64) -> super::Point<f64, U> {
+        super::Point::new(PosX::new(x), PosY::new(y))
+    }
+
+    fn rect<U>(x: f64, y: f64, width: f64, height: f64) -> Rect<f64, U> {
+        Rect::new(point(x, y), Size::new(Width::new(width), Height::new(height)))
+    }
+
+    fn round_trip<Tr, A, B>(transform: &Tr, p: super::Point<f64, A>) -> super::Point<f64, A>
+    where
+        Tr: Invertible + AxisAlignedTransform<f64, A, OutT = f64, OutUnit = B>,
+        Tr::Inverse: AxisAlignedTransform<f64, B, OutT = f64, OutUnit = A>,
+    {
+        transform.inverse().transform_point(transform.transform_point(p))
+    }
+
+    #[test]
+    fn invertible_round_trip() {
+        let scale: ScaleFactor<f64, f64, f64, Point, Pixel> = ScaleFactor(4.0, PhantomData {});
+        assert_eq!(round_trip(&scale, point(3.0, 4.0)), point(3.0, 4.0));
+
+        let matrix: AxisAlignedMatrixTransform<f64, f64, f64, f64, f64, Point, Pixel> =
+            AxisAlignedMatrixTransform::new(2.0, 4.0, 10.0, -6.0);
+        assert_eq!(round_trip(&matrix, point(3.0, 4.0)), point(3.0, 4.0));
+
+        let translation: Translation<f64, Pixel> =
+            Translation(Size::new(Width::new(2.0), Height::new(-1.0)));
+        assert_eq!(round_trip(&translation, point(3.0, 4.0)), point(3.0, 4.0));
+    }
+
+    #[test]
+    fn matrix_inverse() {
+        let rotation: MatrixTransform<f64, Point, Pixel> =
+            MatrixTransform::new([0.0, 1.0, -1.0, 0.0, 5.0, 5.0]);
+        let rotated = rotation.transform_point(point(3.0, 4.0));
+        assert_eq!(rotated, point(1.0, 8.0));
+        assert_eq!(rotation.inverse().transform_point(rotated), point(3.0, 4.0));
+    }
+
+    #[test]
+    fn identities() {
+        let p: super::Point<f64, Pixel> = point(3.0, 4.0);
+
+        let translation = Translation::identity();
+        assert_eq!(Transform::transform_point(&translation, p), p);
+
+        let scale: ScaleFactor<f64, f64, f64, Pixel, Pixel> = ScaleFactor::identity();
+        assert_eq!(Transform::transform_point(&scale, p), p);
+
+        let matrix: AxisAlignedMatrixTransform<f64, f64, f64, f64, f64, Pixel, Pixel> =
+            AxisAlignedMatrixTransform::identity();
+        assert_eq!(Transform::transform_point(&matrix, p), p);
+    }
+
+    #[test]
+    fn transform_points() {
+        let scale: ScaleFactor<f64, f64, f64, Point, Pixel> = ScaleFactor(2.0, PhantomData {});
+        let points = [point(0.0, 0.0), point(1.0, 2.0), point(-3.0, 4.0), point(5.0, -6.0)];
+
+        assert_eq!(
+            scale.transform_points(&points),
+            vec![point(0.0, 0.0), point(2.0, 4.0), point(-6.0, 8.0), point(10.0, -12.0)]
+        );
+    }
+
+    #[test]
+    fn transform_points_in_place() {
+        let shift: MatrixTransform<f64, Pixel, Pixel> =
+            MatrixTransform::new([1.0, 0.0, 0.0, 1.0, 10.0, 20.0]);
+        let mut points = [point(0.0, 0.0), point(1.0, 2.0)];
+        shift.transform_points_in_place(&mut points);
+
+        assert_eq!(points, [point(10.0, 20.0), point(11.0, 22.0)]);
+    }
+
+    #[test]
+    fn matrix3_matches_affine() {
+        let affine: MatrixTransform<f64, Point, Pixel> =
+            MatrixTransform::new([2.0, 1.0, -1.0, 3.0, 5.0, -7.0]);
+        let homogeneous: Matrix3Transform<f64, Point, Pixel> =
+            Matrix3Transform::new([2.0, 1.0, 0.0, -1.0, 3.0, 0.0, 5.0, -7.0, 1.0]);
+
+        let p = point(3.0, 4.0);
+        assert_eq!(homogeneous.transform_point(p), affine.transform_point(p));
+    }
+
+    #[test]
+    fn matrix3_compose() {
+        let scale: Matrix3Transform<f64, Point, Point> =
+            Matrix3Transform::new([2.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 1.0]);
+        let shift: Matrix3Transform<f64, Point, Pixel> =
+            Matrix3Transform::new([1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 10.0, 20.0, 1.0]);
+
+        let combined = shift * scale;
+        assert_eq!(combined.transform_point(point(3.0, 4.0)), point(16.0, 28.0));
+        assert_eq!(
+            (Matrix3Transform::identity() * combined).transform_point(point(3.0, 4.0)),
+            point(16.0, 28.0)
+        );
+    }
+
+    #[test]
+    fn transformed_by() {
+        let matrix: MatrixTransform<f64, Point, Pixel> =
+            MatrixTransform::new([2.0, 0.0, 0.0, 3.0, 0.0, 0.0]);
+        assert_eq!(point(3.0, 4.0).transformed_by(&matrix), point(6.0, 12.0));
+
+        let factor: ScaleFactor<f64, f64, f64, Point, Pixel> = ScaleFactor(2.0, PhantomData {});
+        assert_eq!(point(3.0, 4.0).transformed_by(&factor), point(6.0, 8.0));
+
+        let size: Size<f64, Point> = Size::new(Width::new(3.0), Height::new(4.0));
+        assert_eq!(size.transformed_by(&factor), Size::new(Width::new(6.0), Height::new(8.0)));
+        assert_eq!(Width::<f64, Point>::new(5.0).transformed_by(&factor), Width::new(10.0));
+    }
+
+    #[test]
+    fn scale_factor_from_lengths() {
+        let cm: Width<f64, Point> = Width::new(10.0);
+        let px: Width<f64, Pixel> = Width::new(100.0);
+        let f = ScaleFactor::from_lengths(cm, px);
+
+        assert_eq!(f.transform_width(Width::new(3.0)), Width::new(30.0));
+    }
+
+    #[test]
+    fn transform_circle() {
+        let circle: Circle<f64, Point> = Circle::new(point(1.0, 2.0), Length::new(3.0));
+
+        let uniform: ScaleFactor<f64, f64, f64, Point, Pixel> = ScaleFactor(2.0, PhantomData {});
+        assert_eq!(
+            uniform.transform_circle(circle),
+            Some(Circle::new(point(2.0, 4.0), Length::new(6.0)))
+        );
+
+        let stretch: AxisAlignedMatrixTransform<f64, f64, f64, f64, f64, Point, Pixel> =
+            AxisAlignedMatrixTransform::new(2.0, 3.0, 0.0, 0.0);
+        assert_eq!(stretch.transform_circle(circle), None);
+    }
+
+    #[test]
+    fn transform_circle_flipped() {
+        let circle: Circle<f64, Pixel> = Circle::new(point(1.0, 2.0), Length::new(3.0));
+
+        assert_eq!(
+            FlipX::about_origin().transform_circle(circle),
+            Some(Circle::new(point(-1.0, 2.0), Length::new(3.0)))
+        );
+        assert_eq!(
+            FlipY::new(PosY::new(5.0)).transform_circle(circle),
+            Some(Circle::new(point(1.0, 8.0), Length::new(3.0)))
+        );
+
+        let ndc: AxisAlignedMatrixTransform<f64, f64, f64, f64, f64, Pixel, Pixel> =
+            AxisAlignedMatrixTransform::ndc_to_viewport(&rect(0.0, 0.0, 100.0, 100.0), true);
+        let unit: Circle<f64, Pixel> = Circle::new(point(0.0, 0.0), Length::new(0.5));
+        assert_eq!(
+            ndc.transform_circle(unit),
+            Some(Circle::new(point(50.0, 50.0), Length::new(25.0)))
+        );
+    }
+
+    #[test]
+    fn anisotropic_scale() {
+        let scale: AnisotropicScale<f64, f64, f64, Point, Pixel> = AnisotropicScale::new(2.0, 3.0);
+        let size = Size::new(Width::new(5.0), Height::new(4.0));
+
+        assert_eq!(scale.transform_size(size), Size::new(Width::new(10.0), Height::new(12.0)));
+        assert_eq!(&scale * point(1.0, 1.0), point(2.0, 3.0));
+    }
+
+    #[test]
+    fn matrix_column_major() {
+        let coefficients = [2.0, 1.0, -1.0, 3.0, 5.0, -7.0];
+        let affine: MatrixTransform<f64, Point, Pixel> =
+            MatrixTransform::from_column_major(coefficients);
+
+        assert_eq!(affine.to_column_major(), coefficients);
+        assert_eq!(affine.transform_point(point(1.0, 2.0)), point(2.0 - 2.0 + 5.0, 1.0 + 6.0 - 7.0));
+    }
+
+    #[test]
+    fn from_rects_aligned_contain() {
+        let page: Rect<f64, Point> = rect(0.0, 0.0, 200.0, 100.0);
+        let viewport: Rect<f64, Pixel> = rect(10.0, 0.0, 100.0, 100.0);
+
+        let fit =
+            AxisAlignedMatrixTransform::from_rects_aligned(page, viewport, Fit::Contain, Anchor::Center);
+        assert_eq!(fit.transform_rect(page), rect(10.0, 25.0, 100.0, 50.0));
+    }
+
+    #[test]
+    fn fit_content() {
+        let content: Rect<f64, Point> = rect(50.0, 50.0, 360.0, 90.0);
+        let viewport: Rect<f64, Pixel> = rect(0.0, 0.0, 200.0, 200.0);
+
+        let fit = AxisAlignedMatrixTransform::fit_content(&content, &viewport, Length::new(10.0));
+        let fitted = fit.transform_rect(content);
+        assert_eq!(fitted, rect(10.0, 77.5, 180.0, 45.0));
+        assert!(viewport.contains(&fitted.origin) && viewport.contains(&fitted.corner()));
+        assert_eq!(fitted.origin + fitted.size / 2.0, point(100.0, 100.0));
+    }
+
+    #[test]
+    fn matrix_is_axis_aligned() {
+        let identity: MatrixTransform<f64, Pixel, Pixel> =
+            MatrixTransform::new([1.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+        let rotation: MatrixTransform<f64, Pixel, Pixel> =
+            MatrixTransform::new([0.6, 0.8, -0.8, 0.6, 0.0, 0.0]);
+        let flip: MatrixTransform<f64, Pixel, Pixel> =
+            MatrixTransform::new([-1.0, 0.0, 0.0, 1.0, 10.0, 0.0]);
+
+        assert!(identity.is_axis_aligned(1e-9));
+        assert!(!rotation.is_axis_aligned(1e-9));
+        assert!(flip.is_axis_aligned(1e-9));
+    }
+
+    #[test]
+    fn matrix_to_axis_aligned() {
+        let matrix: MatrixTransform<f64, Point, Pixel> =
+            MatrixTransform::new([2.0, 0.0, 0.0, -3.0, 10.0, 5.0]);
+        let axis_aligned = matrix.to_axis_aligned().unwrap();
+
+        let p = point(1.5, -4.0);
+        assert_eq!(Transform::transform_point(&axis_aligned, p), matrix.transform_point(p));
+
+        let rotation: MatrixTransform<f64, Point, Pixel> =
+            MatrixTransform::new([0.0, 1.0, -1.0, 0.0, 0.0, 0.0]);
+        assert!(rotation.to_axis_aligned().is_none());
+    }
+
+    #[test]
+    fn ndc_to_viewport() {
+        let viewport: Rect<f64, Pixel> = rect(10.0, 20.0, 800.0, 600.0);
+
+        let straight: AxisAlignedMatrixTransform<f64, f64, f64, f64, f64, Point, Pixel> =
+            AxisAlignedMatrixTransform::ndc_to_viewport(&viewport, false);
+        assert_eq!(Transform::transform_point(&straight, point(-1.0, -1.0)), point(10.0, 20.0));
+        assert_eq!(Transform::transform_point(&straight, point(1.0, 1.0)), point(810.0, 620.0));
+
+        let flipped: AxisAlignedMatrixTransform<f64, f64, f64, f64, f64, Point, Pixel> =
+            AxisAlignedMatrixTransform::ndc_to_viewport(&viewport, true);
+        assert_eq!(Transform::transform_point(&flipped, point(-1.0, -1.0)), point(10.0, 620.0));
+        assert_eq!(Transform::transform_point(&flipped, point(1.0, 1.0)), point(810.0, 20.0));
+    }
+
+    #[test]
+    fn relative_scale() {
+        let near: AxisAlignedMatrixTransform<f64, f64, f64, f64, f64, Point, Pixel> =
+            AxisAlignedMatrixTransform::new(2.0, 2.0, 10.0, 0.0);
+        let far = AxisAlignedMatrixTransform::new(4.0, 4.0, -5.0, 3.0);
+
+        assert_eq!(near.relative_scale(&far), Size::new(Width::new(2.0), Height::new(2.0)));
+    }
+
+    #[test]
+    fn transform_in_place() {
+        let shift: Translation<f64, Pixel> =
+            Translation::new(Size::new(Width::new(2.0), Height::new(-1.0)));
+
+        let mut p = point(3.0, 4.0);
+        p.transform_in_place(&shift);
+        assert_eq!(p, point(5.0, 3.0));
+    }
+
+    #[test]
+    fn transformed_iterator() {
+        let matrix: MatrixTransform<f64, Point, Pixel> =
+            MatrixTransform::new([2.0, 0.0, 0.0, 2.0, 1.0, 0.0]);
+        let points = [point(0.0, 0.0), point(1.0, 2.0), point(-3.0, 4.0)];
+
+        let mut transformed = points.iter().cloned().transformed(&matrix);
+        assert_eq!(transformed.size_hint(), (3, Some(3)));
+        assert_eq!(transformed.next(), Some(point(1.0, 0.0)));
+        assert_eq!(transformed.collect::<Vec<_>>(), vec![point(3.0, 4.0), point(-5.0, 8.0)]);
+    }
+
+    #[test]
+    fn flip() {
+        let flip_x: FlipX<f64, Pixel> = FlipX::about_origin();
+        assert_eq!(Transform::transform_point(&flip_x, point(3.0, 4.0)), point(-3.0, 4.0));
+        assert_eq!(flip_x.transform_width(Width::new(2.0)), Width::new(-2.0));
+        assert_eq!(flip_x.transform_rect(rect(1.0, 1.0, 2.0, 3.0)), rect(-3.0, 1.0, 2.0, 3.0));
+
+        let flip_y: FlipY<f64, Pixel> = FlipY::new(PosY::new(10.0));
+        assert_eq!(Transform::transform_point(&flip_y, point(3.0, 4.0)), point(3.0, 16.0));
+        assert_eq!(flip_y.transform_rect(rect(1.0, 1.0, 2.0, 3.0)), rect(1.0, 16.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn matrix_from_three_points() {
+        let known: MatrixTransform<f64, Point, Pixel> =
+            MatrixTransform::new([2.0, 1.0, -1.0, 3.0, 5.0, -7.0]);
+        let from = [point(0.0, 0.0), point(4.0, 1.0), point(-2.0, 3.0)];
+        let to = [
+            known.transform_point(from[0]),
+            known.transform_point(from[1]),
+            known.transform_point(from[2]),
+        ];
+
+        let recovered = MatrixTransform::from_three_points(from, to).unwrap();
+        assert_eq!(recovered.to_column_major(), known.to_column_major());
+
+        let collinear = [point(0.0, 0.0), point(1.0, 1.0), point(2.0, 2.0)];
+        assert!(MatrixTransform::<f64, Point, Pixel>::from_three_points(collinear, to).is_none());
+    }
+
+    #[test]
+    fn scale_factor_to_axis_aligned_matrix() {
+        let scale: ScaleFactor<f64, f64, f64, Point, Pixel> = ScaleFactor(2.5, PhantomData {});
+        let matrix = scale.to_axis_aligned_matrix();
+
+        let p = point(3.0, -4.0);
+        assert_eq!(Transform::transform_point(&matrix, p), Transform::transform_point(&scale, p));
+        assert_eq!(Transform::transform_point(&matrix, p), point(7.5, -10.0));
+    }
+
+    #[test]
+    fn inverse_image_of_rect() {
+        // Zoomed in twice, with the world origin shown at screen point (100, 50)
+        let camera: AxisAlignedMatrixTransform<f64, f64, f64, f64, f64, Point, Pixel> =
+            AxisAlignedMatrixTransform::new(2.0, 2.0, 100.0, 50.0);
+        let screen: Rect<f64, Pixel> = rect(0.0, 0.0, 800.0, 600.0);
+
+        assert_eq!(camera.inverse_image_of_rect(&screen), Some(rect(-50.0, -25.0, 400.0, 300.0)));
+
+        let flat: AxisAlignedMatrixTransform<f64, f64, f64, f64, f64, Point, Pixel> =
+            AxisAlignedMatrixTransform::new(2.0, 0.0, 0.0, 0.0);
+        assert_eq!(flat.inverse_image_of_rect(&screen), None);
+    }
 }
@@ -1,6 +1,14 @@
-use std::ops::{Add, Sub, Mul, Div, Neg, AddAssign, SubAssign, MulAssign, DivAssign};
+use std::ops::{Add, Sub, Mul, Div, Rem, Neg, AddAssign, SubAssign, MulAssign, DivAssign};
+use std::cmp::Ordering;
+use std::error::Error;
 use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter;
+use std::str::FromStr;
 
+use num_traits::{CheckedAdd, Float, Num, SaturatingAdd, Signed, Zero};
+
+use angle::*;
 use oned::*;
 
 pub struct Size<T, Unit> {
@@ -14,7 +22,7 @@ pub struct Point<T, Unit> {
 }
 
 macro_rules! impl_twod {
-    ($s:ident, $x:ident, $y:ident) => {
+    ($s:ident, $x:ident: $tx:ident, $y:ident: $ty:ident) => {
         impl<T: Copy, Unit> Copy for $s<T, Unit> {}
 
         impl<T: Clone, Unit> Clone for $s<T, Unit> {
@@ -26,6 +34,31 @@ macro_rules! impl_twod {
             }
         }
 
+        impl<T, Unit> $s<T, Unit> {
+            /// Retags the value with a different unit without changing it
+            pub fn map_unit<NewUnit>(self) -> $s<T, NewUnit> {
+                $s {
+                    $x: self.$x.map_unit(),
+                    $y: self.$y.map_unit(),
+                }
+            }
+        }
+
+        impl<T: Clone, Unit> $s<T, Unit> {
+            /// Folds the scalar components into a single value, visiting the horizontal one first
+            pub fn fold<A, F: Fn(A, T) -> A>(&self, init: A, f: F) -> A {
+                f(f(init, self.$x.get()), self.$y.get())
+            }
+
+            /// Combines two values component-wise with `f`
+            pub fn zip_with<F: Fn(T, T) -> T>(&self, other: &$s<T, Unit>, f: F) -> $s<T, Unit> {
+                $s {
+                    $x: $tx::new(f(self.$x.get(), other.$x.get())),
+                    $y: $ty::new(f(self.$y.get(), other.$y.get())),
+                }
+            }
+        }
+
         impl<T: Mul<V, Output = W>, V: Clone, W, Unit> Mul<V> for $s<T, Unit> {
             type Output = $s<W, Unit>;
             fn mul(self, scale: V) -> Self::Output {
@@ -78,6 +111,13 @@ macro_rules! impl_twod {
 
         impl<T: Eq, Unit> Eq for $s<T, Unit> {}
 
+        impl<T: Hash, Unit> Hash for $s<T, Unit> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.$x.hash(state);
+                self.$y.hash(state);
+            }
+        }
+
         impl<T: fmt::Debug, Unit> fmt::Debug for $s<T, Unit> {
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
                 write!(f, "TwoD {{ x = {:?}, y = {:?} }}", self.$x, self.$y)
@@ -154,8 +194,235 @@ macro_rules! impl_twod_add_width_height {
     }
 }
 
-impl_twod!(Size, width, height);
-impl_twod!(Point, x, y);
+impl_twod!(Size, width: Width, height: Height);
+impl_twod!(Point, x: PosX, y: PosY);
+
+/// Orders points by x, then by y, so they can be used as keys in ordered collections.
+///
+/// This is only provided for totally ordered scalars.
+impl<T: Ord, Unit> PartialOrd for Point<T, Unit> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord, Unit> Ord for Point<T, Unit> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.x.cmp(&other.x).then_with(|| self.y.cmp(&other.y))
+    }
+}
+
+impl<T, Unit> Size<T, Unit> {
+    pub fn new(width: Width<T, Unit>, height: Height<T, Unit>) -> Self {
+        Self { width, height }
+    }
+
+    /// Returns the size with both dimensions made non-negative
+    pub fn abs(&self) -> Size<T, Unit>
+    where
+        T: Clone + Signed,
+    {
+        Size {
+            width: Width::new(self.width.get().abs()),
+            height: Height::new(self.height.get().abs()),
+        }
+    }
+
+    /// Compares the widths and the heights separately, returning both orderings
+    pub fn partial_cmp_components(
+        &self,
+        other: &Size<T, Unit>,
+    ) -> (Option<Ordering>, Option<Ordering>)
+    where
+        T: PartialOrd,
+    {
+        (
+            self.width.partial_cmp(&other.width),
+            self.height.partial_cmp(&other.height),
+        )
+    }
+
+    /// Adjusts the dimension along the other axis than `driving_axis` to match `ratio`
+    pub fn constrain_to_aspect(&self, ratio: AspectRatio<T>, driving_axis: Axis) -> Size<T, Unit>
+    where
+        T: Clone + Mul<T, Output = T> + Div<T, Output = T>,
+    {
+        let (width, height) = (self.width.clone(), self.height.clone());
+        match driving_axis {
+            Axis::Horizontal => Size::new(width.clone(), ratio.height_for_width(width)),
+            Axis::Vertical => Size::new(ratio.width_for_height(height.clone()), height),
+        }
+    }
+
+    /// Interpolates between this size at `t = 0` and `other` at `t = 1`, easing in and out
+    /// with the smoothstep curve `3t² - 2t³`. The parameter is clamped into `[0, 1]`.
+    pub fn smoothstep(&self, other: &Size<T, Unit>, t: T) -> Size<T, Unit>
+    where
+        T: Float,
+    {
+        *self + (*other - *self) * smoothstep(t)
+    }
+
+    /// Returns the size of the axis-aligned bounding box of a rect of this size rotated by `angle`
+    pub fn rotated_bounds(&self, angle: Angle<T>) -> Size<T, Unit>
+    where
+        T: Float,
+    {
+        let (sin, cos) = angle.radians().sin_cos();
+        let (sin, cos) = (sin.abs(), cos.abs());
+        let (width, height) = (self.width.get(), self.height.get());
+        Size::new(Width::new(width * cos + height * sin), Height::new(width * sin + height * cos))
+    }
+
+    /// Returns the size with the same width and a zero height
+    pub fn width_component(&self) -> Size<T, Unit>
+    where
+        T: Clone + Zero,
+    {
+        Size::new(self.width.clone(), Height::new(T::zero()))
+    }
+
+    /// Returns the size with the same height and a zero width
+    pub fn height_component(&self) -> Size<T, Unit>
+    where
+        T: Clone + Zero,
+    {
+        Size::new(Width::new(T::zero()), self.height.clone())
+    }
+
+    /// Interprets the size as the position reached by moving this far from the coordinate origin
+    pub fn to_point_offset(self) -> Point<T, Unit> {
+        Point {
+            x: PosX::new(self.width.into_inner()),
+            y: PosY::new(self.height.into_inner()),
+        }
+    }
+}
+
+impl<T, Unit> Point<T, Unit> {
+    pub fn new(x: PosX<T, Unit>, y: PosY<T, Unit>) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the displacement from the coordinate origin to this point
+    pub fn to_size_from_origin(self) -> Size<T, Unit> {
+        Size {
+            width: Width::new(self.x.into_inner()),
+            height: Height::new(self.y.into_inner()),
+        }
+    }
+
+    /// Returns the point with the smaller coordinate on each axis
+    pub fn component_min(&self, other: &Point<T, Unit>) -> Point<T, Unit>
+    where
+        T: Clone + PartialOrd,
+    {
+        self.zip_with(other, |a, b| if b < a { b } else { a })
+    }
+
+    /// Returns the point with the larger coordinate on each axis
+    pub fn component_max(&self, other: &Point<T, Unit>) -> Point<T, Unit>
+    where
+        T: Clone + PartialOrd,
+    {
+        self.zip_with(other, |a, b| if b > a { b } else { a })
+    }
+
+    /// Interpolates linearly between this point at `t = 0` and `other` at `t = 1`
+    pub fn lerp(&self, other: &Point<T, Unit>, t: T) -> Point<T, Unit>
+    where
+        T: Clone + Add<T, Output = T> + Sub<T, Output = T> + Mul<T, Output = T>,
+    {
+        self.clone() + (other.clone() - self.clone()) * t
+    }
+
+    /// Like `lerp`, but clamps `t` into `[0, 1]` so the result never overshoots either point
+    pub fn lerp_clamped(&self, other: &Point<T, Unit>, t: T) -> Point<T, Unit>
+    where
+        T: Clone + Num + PartialOrd,
+    {
+        if t <= T::zero() {
+            self.clone()
+        } else if t >= T::one() {
+            other.clone()
+        } else {
+            self.lerp(other, t)
+        }
+    }
+
+    /// Interpolates between this point at `t = 0` and `other` at `t = 1`, easing in and out
+    /// with the smoothstep curve `3t² - 2t³`. The parameter is clamped into `[0, 1]`.
+    pub fn smoothstep(&self, other: &Point<T, Unit>, t: T) -> Point<T, Unit>
+    where
+        T: Float,
+    {
+        self.lerp(other, smoothstep(t))
+    }
+
+    /// Maps this point from `from` into the quadrilateral `to_corners` by bilinear interpolation.
+    ///
+    /// The corners are given in the same order as returned by `Rect::corners`,
+    /// so a point at a corner of `from` lands on the matching entry of `to_corners`.
+    pub fn bilinear_remap<Unit2>(
+        &self,
+        from: &Rect<T, Unit>,
+        to_corners: &[Point<T, Unit2>; 4],
+    ) -> Point<T, Unit2>
+    where
+        T: Clone + Num,
+    {
+        let offset = self.clone() - from.origin.clone();
+        let u = offset.width.get() / from.size.width.get();
+        let v = offset.height.get() / from.size.height.get();
+
+        let start = to_corners[0].lerp(&to_corners[1], u.clone());
+        let end = to_corners[3].lerp(&to_corners[2], u);
+        start.lerp(&end, v)
+    }
+
+    /// Returns the straight-line distance between two points
+    pub fn distance_to(&self, other: &Point<T, Unit>) -> Length<T, Unit>
+    where
+        T: Float,
+    {
+        let d = *other - *self;
+        Length::new(d.width.get().hypot(d.height.get()))
+    }
+
+    /// Wraps each coordinate into `rect`, so that a point leaving one edge reappears at the opposite one.
+    ///
+    /// On an axis where the rect has no extent, the coordinate is moved to the rect's origin.
+    pub fn wrap_within(&self, rect: &Rect<T, Unit>) -> Point<T, Unit>
+    where
+        T: Clone + Num,
+    {
+        let wrap_from = |value: T, start: T, extent: T| start.clone() + wrap(value - start, extent);
+        Point {
+            x: PosX::new(wrap_from(self.x.get(), rect.origin.x.get(), rect.size.width.get())),
+            y: PosY::new(wrap_from(self.y.get(), rect.origin.y.get(), rect.size.height.get())),
+        }
+    }
+
+    /// Brings the point into `rect`, either by clamping it to the nearest edge or by wrapping it
+    pub fn confine(&self, rect: &Rect<T, Unit>, mode: ConfineMode) -> Point<T, Unit>
+    where
+        T: Clone + Num + PartialOrd,
+    {
+        match mode {
+            ConfineMode::Clamp => self.component_max(&rect.origin).component_min(&rect.corner()),
+            ConfineMode::Wrap => self.wrap_within(rect),
+        }
+    }
+}
+
+/// How `Point::confine` treats a point outside the rect
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ConfineMode {
+    /// Move the point to the nearest point on the boundary
+    Clamp,
+    /// Wrap the point around to the opposite edge, as in `Point::wrap_within`
+    Wrap,
+}
 
 impl_twod_add_width_height!(Size, width, height);
 impl_twod_add_width_height!(Point, x, y);
@@ -240,11 +507,91 @@ macro_rules! impl_twod_add {
                 self.y -= other.height;
             }
         }
+
+        /// Adds two sizes component-wise, returning `None` if either component overflows
+        impl<T: CheckedAdd, Unit> $length<T, Unit> {
+            pub fn checked_add(&self, other: &$length<T, Unit>) -> Option<Self> {
+                Some($length {
+                    width: self.width.checked_add(&other.width)?,
+                    height: self.height.checked_add(&other.height)?,
+                })
+            }
+        }
+
+        /// Adds two sizes component-wise, clamping each component on overflow
+        impl<T: SaturatingAdd, Unit> $length<T, Unit> {
+            pub fn saturating_add(&self, other: &$length<T, Unit>) -> Self {
+                $length {
+                    width: self.width.saturating_add(&other.width),
+                    height: self.height.saturating_add(&other.height),
+                }
+            }
+        }
+
+        /// Offsets a point by a size, returning `None` if either coordinate overflows
+        impl<T: CheckedAdd, Unit> $pos<T, Unit> {
+            pub fn checked_add(&self, other: &$length<T, Unit>) -> Option<Self> {
+                Some($pos {
+                    x: self.x.checked_add(&other.width)?,
+                    y: self.y.checked_add(&other.height)?,
+                })
+            }
+        }
+
+        /// Offsets a point by a size, clamping each coordinate on overflow
+        impl<T: SaturatingAdd, Unit> $pos<T, Unit> {
+            pub fn saturating_add(&self, other: &$length<T, Unit>) -> Self {
+                $pos {
+                    x: self.x.saturating_add(&other.width),
+                    y: self.y.saturating_add(&other.height),
+                }
+            }
+        }
     }
 }
 
 impl_twod_add!(Size, Point);
 
+/// Eases `t` clamped into `[0, 1]` with zero slope at both ends
+fn smoothstep<T: Float>(t: T) -> T {
+    let t = t.max(T::zero()).min(T::one());
+    let (two, three) = (T::one() + T::one(), T::one() + T::one() + T::one());
+    t * t * (three - two * t)
+}
+
+/// Wraps a value into `[0, modulus)`, also for negative values.
+/// Everything wraps to zero when the modulus is zero.
+fn wrap<T: Clone + Num>(value: T, modulus: T) -> T {
+    if modulus.is_zero() {
+        return T::zero();
+    }
+    (value % modulus.clone() + modulus.clone()) % modulus
+}
+
+/// Component-wise modulo, wrapping each component into `[0, modulus)`.
+/// A component with a zero modulus becomes zero.
+impl<T: Clone + Num, Unit> Rem<Size<T, Unit>> for Size<T, Unit> {
+    type Output = Size<T, Unit>;
+    fn rem(self, modulus: Size<T, Unit>) -> Self::Output {
+        Size {
+            width: Width::new(wrap(self.width.into_inner(), modulus.width.into_inner())),
+            height: Height::new(wrap(self.height.into_inner(), modulus.height.into_inner())),
+        }
+    }
+}
+
+/// Component-wise modulo, wrapping each coordinate into `[0, modulus)`.
+/// A coordinate with a zero modulus becomes zero.
+impl<T: Clone + Num, Unit> Rem<Size<T, Unit>> for Point<T, Unit> {
+    type Output = Point<T, Unit>;
+    fn rem(self, modulus: Size<T, Unit>) -> Self::Output {
+        Point {
+            x: PosX::new(wrap(self.x.into_inner(), modulus.width.into_inner())),
+            y: PosY::new(wrap(self.y.into_inner(), modulus.height.into_inner())),
+        }
+    }
+}
+
 pub struct Rect<T, Unit> {
     pub origin: Point<T, Unit>,
     pub size: Size<T, Unit>,
@@ -270,76 +617,1413 @@ impl<T, Unit> Rect<T, Unit> {
     {
         self.origin.clone() + self.size.clone()
     }
-}
 
-impl<T: Copy, Unit> Copy for Rect<T, Unit> {}
+    /// Like `corner`, but returns `None` if either coordinate overflows
+    pub fn checked_corner(&self) -> Option<Point<T, Unit>>
+    where
+        T: CheckedAdd,
+    {
+        self.origin.checked_add(&self.size)
+    }
 
-impl<T: Clone, Unit> Clone for Rect<T, Unit> {
-    fn clone(&self) -> Self {
+    /// Returns the origin and the opposite corner
+    pub fn to_min_max(&self) -> (Point<T, Unit>, Point<T, Unit>)
+    where
+        T: Clone + Add<T, Output = T>,
+    {
+        (self.origin.clone(), self.corner())
+    }
+
+    /// Creates a rect from its origin and the opposite corner, the inverse of `to_min_max`
+    pub fn from_min_max(min: Point<T, Unit>, max: Point<T, Unit>) -> Rect<T, Unit>
+    where
+        T: Clone + Sub<T, Output = T>,
+    {
+        Rect::from_points(min, max)
+    }
+
+    /// Retags the rect with a different unit without changing it
+    pub fn map_unit<NewUnit>(self) -> Rect<T, NewUnit> {
         Rect {
-            origin: self.origin.clone(),
-            size: self.size.clone(),
+            origin: self.origin.map_unit(),
+            size: self.size.map_unit(),
         }
     }
-}
 
-impl<T: PartialEq, Unit> PartialEq for Rect<T, Unit> {
-    fn eq(&self, other: &Self) -> bool {
-        self.origin == other.origin && self.size == other.size
+    /// Returns the four corners, starting at the origin and going first along the x axis
+    pub fn corners(&self) -> [Point<T, Unit>; 4]
+    where
+        T: Clone + Add<T, Output = T>,
+    {
+        [self.corner_at(0), self.corner_at(1), self.corner_at(2), self.corner_at(3)]
     }
-}
 
-impl<T: Eq, Unit> Eq for Rect<T, Unit> {}
+    /// Lazily yields the same four corners as `corners`
+    pub fn corner_iter(&self) -> impl Iterator<Item = Point<T, Unit>>
+    where
+        T: Clone + Add<T, Output = T>,
+    {
+        let rect = self.clone();
+        (0..4).map(move |i| rect.corner_at(i))
+    }
 
-impl<T: fmt::Debug, Unit> fmt::Debug for Rect<T, Unit> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Rect {{ origin = {:?}, size = {:?} }}", self.origin, self.size)
+    fn corner_at(&self, i: usize) -> Point<T, Unit>
+    where
+        T: Clone + Add<T, Output = T>,
+    {
+        match i {
+            0 => self.origin.clone(),
+            1 => self.origin.clone() + self.size.width.clone(),
+            2 => self.corner(),
+            _ => self.origin.clone() + self.size.height.clone(),
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    pub use super::*;
+    /// Moves the top edge down by `amount`, keeping the other edges in place
+    pub fn inset_top(&self, amount: Height<T, Unit>) -> Rect<T, Unit>
+    where
+        T: Clone + Add<T, Output = T> + Sub<T, Output = T>,
+    {
+        let Rect { origin, size } = self.clone();
+        Rect::new(
+            Point::new(origin.x, origin.y + amount.clone()),
+            Size::new(size.width, size.height - amount),
+        )
+    }
 
-    struct Pixel;
+    /// Moves the left edge right by `amount`, keeping the other edges in place
+    pub fn inset_left(&self, amount: Width<T, Unit>) -> Rect<T, Unit>
+    where
+        T: Clone + Add<T, Output = T> + Sub<T, Output = T>,
+    {
+        let Rect { origin, size } = self.clone();
+        Rect::new(
+            Point::new(origin.x + amount.clone(), origin.y),
+            Size::new(size.width - amount, size.height),
+        )
+    }
 
-    #[test]
-    fn construct_size() {
-        let w: Width<f64, Pixel> = Width::new(40.0);
-        let h: Height<f64, Pixel> = Height::new(20.0);
-        let size = Size {
-            width: w,
-            height: h,
-        };
+    /// Moves the right edge left by `amount`, keeping the other edges in place
+    pub fn inset_right(&self, amount: Width<T, Unit>) -> Rect<T, Unit>
+    where
+        T: Clone + Sub<T, Output = T>,
+    {
+        let Rect { origin, size } = self.clone();
+        Rect::new(origin, Size::new(size.width - amount, size.height))
+    }
 
-        assert_eq!(
-            size,
-            Size {
-                width: Width::<f64, Pixel>::new(40.0),
-                height: Height::<f64, Pixel>::new(20.0),
-            }
-        );
+    /// Moves the bottom edge up by `amount`, keeping the other edges in place
+    pub fn inset_bottom(&self, amount: Height<T, Unit>) -> Rect<T, Unit>
+    where
+        T: Clone + Sub<T, Output = T>,
+    {
+        let Rect { origin, size } = self.clone();
+        Rect::new(origin, Size::new(size.width, size.height - amount))
     }
 
-    #[test]
-    fn size_is_clone() {
-        let size = Size {
-            width: Width::<f64, Pixel>::new(40.0),
-            height: Height::<f64, Pixel>::new(20.0),
-        };
-        let size_clone = size.clone();
+    /// Returns true if `p` lies inside the rect or on its boundary
+    pub fn contains(&self, p: &Point<T, Unit>) -> bool
+    where
+        T: Clone + PartialOrd + Add<T, Output = T>,
+    {
+        let corner = self.corner();
+        p.x >= self.origin.x && p.y >= self.origin.y && p.x <= corner.x && p.y <= corner.y
+    }
 
-        assert_eq!(size, size_clone);
+    /// Returns the span of x coordinates shared by both rects,
+    /// or `None` if they do not overlap horizontally
+    pub fn overlap_x(&self, other: &Rect<T, Unit>) -> Option<Interval<T, Unit>>
+    where
+        T: Clone + PartialOrd + Add<T, Output = T>,
+    {
+        let (corner, other_corner) = (self.corner(), other.corner());
+        overlap(
+            (self.origin.x.get(), corner.x.get()),
+            (other.origin.x.get(), other_corner.x.get()),
+        )
     }
 
-    #[test]
-    fn size_is_copy() {
-        let size = Size {
-            width: Width::<f64, Pixel>::new(40.0),
-            height: Height::<f64, Pixel>::new(20.0),
+    /// Returns the span of y coordinates shared by both rects,
+    /// or `None` if they do not overlap vertically
+    pub fn overlap_y(&self, other: &Rect<T, Unit>) -> Option<Interval<T, Unit>>
+    where
+        T: Clone + PartialOrd + Add<T, Output = T>,
+    {
+        let (corner, other_corner) = (self.corner(), other.corner());
+        overlap(
+            (self.origin.y.get(), corner.y.get()),
+            (other.origin.y.get(), other_corner.y.get()),
+        )
+    }
+
+    /// Returns how deep the rects overlap along each axis, or `None` if they do not overlap
+    pub fn penetration(&self, other: &Rect<T, Unit>) -> Option<Size<T, Unit>>
+    where
+        T: Clone + PartialOrd + Add<T, Output = T> + Sub<T, Output = T>,
+    {
+        let x = self.overlap_x(other)?;
+        let y = self.overlap_y(other)?;
+        Some(Size::new(
+            Width::new(x.end.into_inner() - x.start.into_inner()),
+            Height::new(y.end.into_inner() - y.start.into_inner()),
+        ))
+    }
+
+    /// Returns the shortest displacement that moves this rect out of `other`,
+    /// or `None` if they do not overlap.
+    ///
+    /// The displacement is along the axis with the smaller overlap, and points away from `other`.
+    pub fn minimum_translation_vector(&self, other: &Rect<T, Unit>) -> Option<Size<T, Unit>>
+    where
+        T: Clone + Num + PartialOrd,
+    {
+        let depth = self.penetration(other)?;
+        let (depth_x, depth_y) = (depth.width.into_inner(), depth.height.into_inner());
+
+        // Comparing the sums of the edges is comparing the centers, without dividing by two
+        let (corner, other_corner) = (self.corner(), other.corner());
+        let away = |start: T, end: T, other_start: T, other_end: T, depth: T| {
+            if start + end < other_start + other_end {
+                T::zero() - depth
+            } else {
+                depth
+            }
         };
-        let size_copy = size;
 
-        assert_eq!(size, size_copy);
+        Some(if depth_x <= depth_y {
+            let dx = away(
+                self.origin.x.get(),
+                corner.x.get(),
+                other.origin.x.get(),
+                other_corner.x.get(),
+                depth_x,
+            );
+            Size::new(Width::new(dx), Height::new(T::zero()))
+        } else {
+            let dy = away(
+                self.origin.y.get(),
+                corner.y.get(),
+                other.origin.y.get(),
+                other_corner.y.get(),
+                depth_y,
+            );
+            Size::new(Width::new(T::zero()), Height::new(dy))
+        })
+    }
+
+    /// Returns true if the rect has no area, i.e. its width or height is zero or negative
+    pub fn is_empty(&self) -> bool
+    where
+        T: PartialOrd + Zero,
+    {
+        self.size.width <= Width::new(T::zero()) || self.size.height <= Height::new(T::zero())
+    }
+
+    /// Returns the smallest rect containing both rects
+    pub fn union(&self, other: &Rect<T, Unit>) -> Rect<T, Unit>
+    where
+        T: Clone + PartialOrd + Add<T, Output = T> + Sub<T, Output = T>,
+    {
+        let min = self.origin.component_min(&other.origin);
+        let max = self.corner().component_max(&other.corner());
+        Rect::from_points(min, max)
+    }
+
+    /// Like `union`, but an empty rect does not contribute to the result.
+    ///
+    /// If both rects are empty, returns `self`.
+    pub fn union_non_empty(&self, other: &Rect<T, Unit>) -> Rect<T, Unit>
+    where
+        T: Clone + PartialOrd + Zero + Add<T, Output = T> + Sub<T, Output = T>,
+    {
+        if other.is_empty() {
+            self.clone()
+        } else if self.is_empty() {
+            other.clone()
+        } else {
+            self.union(other)
+        }
+    }
+
+    /// Grows the rect by `width` on the left and right and by `height` on the top and bottom.
+    ///
+    /// Negative amounts shrink it instead.
+    pub fn inflate(&self, width: Width<T, Unit>, height: Height<T, Unit>) -> Rect<T, Unit>
+    where
+        T: Clone + Add<T, Output = T> + Sub<T, Output = T>,
+    {
+        Rect::new(
+            self.origin.clone() - Size::new(width.clone(), height.clone()),
+            Size::new(
+                self.size.width.clone() + width.clone() + width,
+                self.size.height.clone() + height.clone() + height,
+            ),
+        )
+    }
+
+    /// Like `contains`, but also accepts points up to `tolerance` outside the rect
+    pub fn contains_with_tolerance(&self, p: &Point<T, Unit>, tolerance: Length<T, Unit>) -> bool
+    where
+        T: Clone + PartialOrd + Add<T, Output = T> + Sub<T, Output = T>,
+    {
+        let tolerance = tolerance.into_inner();
+        self.inflate(Width::new(tolerance.clone()), Height::new(tolerance)).contains(p)
+    }
+
+    /// Returns a rect of zero size at `origin`, a starting point for repeated `expand_to_include`
+    pub fn empty_at(origin: Point<T, Unit>) -> Rect<T, Unit>
+    where
+        T: Zero,
+    {
+        Rect::new(origin, Size::new(Width::new(T::zero()), Height::new(T::zero())))
+    }
+
+    /// Returns the smallest rect containing all of `points`, or `None` if there are none
+    pub fn bounding<I: IntoIterator<Item = Point<T, Unit>>>(points: I) -> Option<Self>
+    where
+        T: Clone + PartialOrd + Sub<T, Output = T>,
+    {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let (min, max) = points.fold((first.clone(), first), |(min, max), p| {
+            (min.component_min(&p), max.component_max(&p))
+        });
+        Some(Rect::from_points(min, max))
+    }
+
+    /// Grows the rect just enough to contain `p`
+    pub fn expand_to_include(&mut self, p: &Point<T, Unit>)
+    where
+        T: Clone + PartialOrd + Add<T, Output = T> + Sub<T, Output = T>,
+    {
+        let min = self.origin.component_min(p);
+        let max = self.corner().component_max(p);
+        *self = Rect::from_points(min, max);
+    }
+
+    /// Covers the rect with tiles of the given size, row by row.
+    ///
+    /// Tiles in the last row and column are clipped to the rect.
+    /// A tile with a zero or negative dimension yields nothing.
+    pub fn tile(&self, tile: Size<T, Unit>) -> impl Iterator<Item = Rect<T, Unit>>
+    where
+        T: Copy + PartialOrd + Zero + Sub<T, Output = T>,
+    {
+        let origin = self.origin;
+        let corner = self.corner();
+        let valid = tile.width.get() > T::zero() && tile.height.get() > T::zero();
+
+        let rows = iter::successors(if valid { Some(origin.y) } else { None }, move |y| {
+            Some(*y + tile.height)
+        });
+        rows.take_while(move |y| *y < corner.y).flat_map(move |y| {
+            let height = if corner.y - y < tile.height { corner.y - y } else { tile.height };
+            iter::successors(Some(origin.x), move |x| Some(*x + tile.width))
+                .take_while(move |x| *x < corner.x)
+                .map(move |x| {
+                    let width = if corner.x - x < tile.width { corner.x - x } else { tile.width };
+                    Rect::new(Point::new(x, y), Size::new(width, height))
+                })
+        })
+    }
+
+    /// Folds the scalar components into a single value, visiting the origin before the size
+    pub fn fold<A, F: Fn(A, T) -> A>(&self, init: A, f: F) -> A
+    where
+        T: Clone,
+    {
+        self.size.fold(self.origin.fold(init, &f), &f)
+    }
+
+    /// Compares two rects by their area, e.g. for sorting with `sort_by`
+    pub fn area_cmp(&self, other: &Rect<T, Unit>) -> Option<Ordering>
+    where
+        T: Clone + Mul<T, Output = T> + PartialOrd,
+    {
+        let area = |r: &Rect<T, Unit>| r.size.width.get() * r.size.height.get();
+        area(self).partial_cmp(&area(other))
+    }
+
+    /// Reflects the rect through the coordinate origin.
+    ///
+    /// The far corner becomes the new origin, so the size is unchanged and stays positive.
+    pub fn mirror_origin(&self) -> Rect<T, Unit>
+    where
+        T: Clone + Add<T, Output = T> + Neg<Output = T>,
+    {
+        Rect {
+            origin: -self.corner(),
+            size: self.size.clone(),
+        }
+    }
+}
+
+impl<T: Copy, Unit> Copy for Rect<T, Unit> {}
+
+impl<T: Clone, Unit> Clone for Rect<T, Unit> {
+    fn clone(&self) -> Self {
+        Rect {
+            origin: self.origin.clone(),
+            size: self.size.clone(),
+        }
+    }
+}
+
+impl<T: PartialEq, Unit> PartialEq for Rect<T, Unit> {
+    fn eq(&self, other: &Self) -> bool {
+        self.origin == other.origin && self.size == other.size
+    }
+}
+
+impl<T: Eq, Unit> Eq for Rect<T, Unit> {}
+
+impl<T: Hash, Unit> Hash for Rect<T, Unit> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.origin.hash(state);
+        self.size.hash(state);
+    }
+}
+
+impl<T: fmt::Debug, Unit> fmt::Debug for Rect<T, Unit> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Rect {{ origin = {:?}, size = {:?} }}", self.origin, self.size)
+    }
+}
+
+/// Intersects two `(start, end)` spans, returning `None` unless the result is non-empty
+fn overlap<T: PartialOrd, Unit>(a: (T, T), b: (T, T)) -> Option<Interval<T, Unit>> {
+    let start = if b.0 > a.0 { b.0 } else { a.0 };
+    let end = if b.1 < a.1 { b.1 } else { a.1 };
+    if start < end {
+        Some(Interval::new(Position::new(start), Position::new(end)))
+    } else {
+        None
+    }
+}
+
+/// Returns the times at which a span moving with `velocity` starts and stops overlapping
+/// a static one, or `None` if a span that does not move never overlaps
+fn sweep_axis<T: Float>(moving: (T, T), fixed: (T, T), velocity: T) -> Option<(T, T)> {
+    if velocity > T::zero() {
+        Some(((fixed.0 - moving.1) / velocity, (fixed.1 - moving.0) / velocity))
+    } else if velocity < T::zero() {
+        Some(((fixed.1 - moving.0) / velocity, (fixed.0 - moving.1) / velocity))
+    } else if moving.0 < fixed.1 && fixed.0 < moving.1 {
+        Some((T::neg_infinity(), T::infinity()))
+    } else {
+        None
+    }
+}
+
+/// Computes a bounding rect from points that arrive one at a time
+///
+/// This gives the same result as `Rect::bounding` without collecting the points first.
+pub struct BoundsAccumulator<T, Unit> {
+    bounds: Option<(Point<T, Unit>, Point<T, Unit>)>,
+}
+
+impl<T, Unit> BoundsAccumulator<T, Unit> {
+    pub fn new() -> Self {
+        BoundsAccumulator { bounds: None }
+    }
+
+    /// Grows the bounds to include `point`
+    pub fn push(&mut self, point: Point<T, Unit>)
+    where
+        T: Clone + PartialOrd,
+    {
+        self.bounds = Some(match self.bounds.take() {
+            Some((min, max)) => (min.component_min(&point), max.component_max(&point)),
+            None => (point.clone(), point),
+        });
+    }
+
+    /// Returns the smallest rect containing every pushed point, or `None` if there were none
+    pub fn finish(self) -> Option<Rect<T, Unit>>
+    where
+        T: Clone + Sub<T, Output = T>,
+    {
+        self.bounds.map(|(min, max)| Rect::from_points(min, max))
+    }
+}
+
+impl<T, Unit> Default for BoundsAccumulator<T, Unit> {
+    fn default() -> Self {
+        BoundsAccumulator::new()
+    }
+}
+
+impl<T: Clone, Unit> Clone for BoundsAccumulator<T, Unit> {
+    fn clone(&self) -> Self {
+        BoundsAccumulator {
+            bounds: self.bounds.clone(),
+        }
+    }
+}
+
+impl<T: fmt::Debug, Unit> fmt::Debug for BoundsAccumulator<T, Unit> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "BoundsAccumulator {{ bounds = {:?} }}", self.bounds)
+    }
+}
+
+/// How to round the edges of a rect when converting it to integer coordinates
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RoundMode {
+    /// Round both edges on each axis down
+    Floor,
+    /// Round both edges on each axis up
+    Ceil,
+    /// Round both edges on each axis to the nearest integer
+    Round,
+    /// Grow the rect to the smallest enclosing integer rect
+    Outward,
+    /// Shrink the rect to the largest integer rect it contains
+    Inward,
+}
+
+impl<T: Float, Unit> Rect<T, Unit> {
+    /// Scales the width and height by the matching components of `scale`, keeping the center fixed
+    pub fn scale_non_uniform_about_center(&self, scale: Size<T, Unit>) -> Rect<T, Unit> {
+        let size = Size::new(
+            self.size.width * scale.width.get(),
+            self.size.height * scale.height.get(),
+        );
+        let two = T::one() + T::one();
+        Rect::new(self.origin + (self.size - size) / two, size)
+    }
+
+    /// Returns the largest rect with aspect `ratio` that fits inside this one, sharing its center.
+    ///
+    /// The result is only ever cropped along one axis, and never grows.
+    pub fn shrink_to_aspect(&self, ratio: AspectRatio<T>) -> Rect<T, Unit> {
+        let size = if AspectRatio::from_size(&self.size) > ratio {
+            Size::new(ratio.width_for_height(self.size.height), self.size.height)
+        } else {
+            Size::new(self.size.width, ratio.height_for_width(self.size.width))
+        };
+        let two = T::one() + T::one();
+        Rect::new(self.origin + (self.size - size) / two, size)
+    }
+
+    /// Returns the signed distance from the edge of the rect to `p`,
+    /// negative inside the rect and positive outside of it
+    pub fn signed_distance(&self, p: &Point<T, Unit>) -> Length<T, Unit> {
+        let two = T::one() + T::one();
+        let half = self.size / two;
+        let offset = *p - (self.origin + half);
+        let dx = offset.width.get().abs() - half.width.get();
+        let dy = offset.height.get().abs() - half.height.get();
+
+        let outside = dx.max(T::zero()).hypot(dy.max(T::zero()));
+        let inside = dx.max(dy).min(T::zero());
+        Length::new(outside + inside)
+    }
+
+    /// Splits the rect into `count` columns of equal width, with `gap` between neighbouring ones.
+    ///
+    /// The last column ends exactly on the right edge of the rect, absorbing any rounding error.
+    pub fn columns(&self, count: usize, gap: Width<T, Unit>) -> Vec<Rect<T, Unit>> {
+        if count == 0 {
+            return Vec::new();
+        }
+        let n = T::from(count).expect("column count out of range");
+        let width = (self.size.width - gap * (n - T::one())) / n;
+        let right = self.corner().x;
+
+        (0..count)
+            .map(|i| {
+                let x = self.origin.x + (width + gap) * T::from(i).expect("column index out of range");
+                let width = if i + 1 == count { right - x } else { width };
+                Rect::new(Point::new(x, self.origin.y), Size::new(width, self.size.height))
+            })
+            .collect()
+    }
+
+    /// Splits the rect into `count` rows of equal height, with `gap` between neighbouring ones.
+    ///
+    /// The last row ends exactly on the bottom edge of the rect, absorbing any rounding error.
+    pub fn rows(&self, count: usize, gap: Height<T, Unit>) -> Vec<Rect<T, Unit>> {
+        if count == 0 {
+            return Vec::new();
+        }
+        let n = T::from(count).expect("row count out of range");
+        let height = (self.size.height - gap * (n - T::one())) / n;
+        let bottom = self.corner().y;
+
+        (0..count)
+            .map(|i| {
+                let y = self.origin.y + (height + gap) * T::from(i).expect("row index out of range");
+                let height = if i + 1 == count { bottom - y } else { height };
+                Rect::new(Point::new(self.origin.x, y), Size::new(self.size.width, height))
+            })
+            .collect()
+    }
+
+    /// Moves the rect by `velocity` and returns the fraction of it travelled before first
+    /// touching `obstacle`, or `None` if they do not touch within the whole movement.
+    ///
+    /// A rect that already overlaps the obstacle collides at `0`.
+    pub fn sweep(&self, velocity: Size<T, Unit>, obstacle: &Rect<T, Unit>) -> Option<T> {
+        let (corner, obstacle_corner) = (self.corner(), obstacle.corner());
+        let (entry_x, exit_x) = sweep_axis(
+            (self.origin.x.get(), corner.x.get()),
+            (obstacle.origin.x.get(), obstacle_corner.x.get()),
+            velocity.width.get(),
+        )?;
+        let (entry_y, exit_y) = sweep_axis(
+            (self.origin.y.get(), corner.y.get()),
+            (obstacle.origin.y.get(), obstacle_corner.y.get()),
+            velocity.height.get(),
+        )?;
+
+        let entry = entry_x.max(entry_y);
+        let exit = exit_x.min(exit_y);
+        if entry >= exit || entry > T::one() || exit < T::zero() {
+            None
+        } else {
+            Some(entry.max(T::zero()))
+        }
+    }
+
+    /// Converts the rect to integer coordinates, rounding its edges according to `mode`.
+    ///
+    /// With `Inward`, a rect that contains no whole cell on some axis gets a zero size on that axis.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a rounded edge does not fit in an `i32`,
+    /// or if the rounded width or height does not fit in an `i32` even though both edges do.
+    pub fn to_int(&self, mode: RoundMode) -> Rect<i32, Unit> {
+        let start = |value: T| match mode {
+            RoundMode::Floor | RoundMode::Outward => value.floor(),
+            RoundMode::Ceil | RoundMode::Inward => value.ceil(),
+            RoundMode::Round => value.round(),
+        };
+        let end = |value: T| match mode {
+            RoundMode::Floor | RoundMode::Inward => value.floor(),
+            RoundMode::Ceil | RoundMode::Outward => value.ceil(),
+            RoundMode::Round => value.round(),
+        };
+        let to_i32 = |value: T| value.to_i32().expect("rect coordinate out of i32 range");
+
+        let corner = self.corner();
+        let (left, top) = (to_i32(start(self.origin.x.get())), to_i32(start(self.origin.y.get())));
+        let (right, bottom) = (to_i32(end(corner.x.get())), to_i32(end(corner.y.get())));
+
+        let extent = |start: i32, end: i32| {
+            end.checked_sub(start).expect("rect size out of i32 range").max(0)
+        };
+
+        Rect::new(
+            Point::new(PosX::new(left), PosY::new(top)),
+            Size::new(Width::new(extent(left, right)), Height::new(extent(top, bottom))),
+        )
+    }
+}
+
+impl<Unit> Rect<i32, Unit> {
+    /// Returns the center, rounded down to whole cells.
+    ///
+    /// Adds half the size to the origin instead of averaging the edges,
+    /// so it does not overflow for any rect whose corner fits in `i32`.
+    pub fn center_floor(&self) -> Point<i32, Unit> {
+        Point::new(
+            self.origin.x + Width::new(self.size.width.get().div_euclid(2)),
+            self.origin.y + Height::new(self.size.height.get().div_euclid(2)),
+        )
+    }
+
+    /// Iterates over the integer cells on the edge of the rect, without the interior.
+    ///
+    /// Goes along the top row, then the left and right cells of each middle row,
+    /// then the bottom row. Each cell is yielded once, even for rects one cell wide or tall.
+    pub fn border_cells(&self) -> impl Iterator<Item = Point<i32, Unit>> {
+        // The far edges are computed in i64, since they may not fit in i32 for an empty rect
+        let (left, top) = (i64::from(self.origin.x.get()), i64::from(self.origin.y.get()));
+        let (width, height) = if self.size.width.get() > 0 && self.size.height.get() > 0 {
+            (i64::from(self.size.width.get()), i64::from(self.size.height.get()))
+        } else {
+            (0, 0)
+        };
+        let (right, bottom) = (left + width - 1, top + height - 1);
+
+        let top_row = (left..=right).map(move |x| (x, top));
+        let sides = (top + 1..bottom).flat_map(move |y| {
+            iter::once(left)
+                .chain(if right > left { Some(right) } else { None })
+                .map(move |x| (x, y))
+        });
+        let bottom_row = (left..=right).filter(move |_| bottom > top).map(move |x| (x, bottom));
+
+        top_row
+            .chain(sides)
+            .chain(bottom_row)
+            .map(|(x, y)| Point::new(PosX::new(x as i32), PosY::new(y as i32)))
+    }
+
+    /// Iterates over the integer cells of the rect in rings spiralling out from `center_floor`.
+    ///
+    /// Each ring holds the cells at the same Chebyshev distance from the center, so the first cell
+    /// is always the center. A ring is walked clockwise, as seen with y pointing down: down its
+    /// right side, left along its bottom, up its left side and right along its top, with its four
+    /// corners last. Every ring is clipped to the rect, so cells are produced lazily and a long,
+    /// thin rect costs no more than the cells it yields.
+    pub fn spiral_cells(&self) -> impl Iterator<Item = Point<i32, Unit>> {
+        // Computed in i64, since the outer rings may reach past the range of i32
+        let (left, top) = (i64::from(self.origin.x.get()), i64::from(self.origin.y.get()));
+        let (right, bottom) = (
+            left + i64::from(self.size.width.get()) - 1,
+            top + i64::from(self.size.height.get()) - 1,
+        );
+        let center = self.center_floor();
+        let (cx, cy) = (i64::from(center.x.get()), i64::from(center.y.get()));
+        let rings = if self.is_empty() {
+            0..0
+        } else {
+            0..(cx - left).max(right - cx).max(cy - top).max(bottom - cy) + 1
+        };
+
+        // The cells from `start` to `end` of one side, or none if the side lies outside the rect
+        let side = |start: i64, end: i64, min: i64, max: i64, inside: bool| {
+            let end = if inside { end.min(max) } else { i64::MIN };
+            start.max(min)..=end
+        };
+
+        rings
+            .flat_map(move |r| {
+                let (near_x, far_x, near_y, far_y) = (cx - r, cx + r, cy - r, cy + r);
+                let right_side = side(near_y + 1, far_y - 1, top, bottom, far_x <= right)
+                    .map(move |y| (far_x, y));
+                let bottom_side = side(near_x + 1, far_x - 1, left, right, far_y <= bottom)
+                    .rev()
+                    .map(move |x| (x, far_y));
+                let left_side = side(near_y + 1, far_y - 1, top, bottom, near_x >= left)
+                    .rev()
+                    .map(move |y| (near_x, y));
+                let top_side = side(near_x + 1, far_x - 1, left, right, near_y >= top)
+                    .map(move |x| (x, near_y));
+                let corners = IntoIterator::into_iter([
+                    (far_x, far_y),
+                    (near_x, far_y),
+                    (near_x, near_y),
+                    (far_x, near_y),
+                ])
+                .take(if r == 0 { 1 } else { 4 })
+                .filter(move |&(x, y)| x >= left && x <= right && y >= top && y <= bottom);
+
+                right_side.chain(bottom_side).chain(left_side).chain(top_side).chain(corners)
+            })
+            .map(|(x, y)| Point::new(PosX::new(x as i32), PosY::new(y as i32)))
+    }
+}
+
+/// An error returned when parsing a `Point` or a `Size` from a string
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ParseError<E> {
+    /// The input did not consist of two components with a separator between them
+    MissingSeparator,
+    /// One of the components could not be parsed as a scalar
+    InvalidComponent(E),
+}
+
+impl<E: fmt::Display> fmt::Display for ParseError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::MissingSeparator => {
+                write!(f, "expected two components separated by ',' (or 'x' for a size)")
+            }
+            ParseError::InvalidComponent(e) => write!(f, "invalid component: {}", e),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> Error for ParseError<E> {}
+
+fn parse_pair<T: FromStr>(s: &str, separators: &[char]) -> Result<(T, T), ParseError<T::Err>> {
+    let mut parts = s.splitn(2, separators);
+    match (parts.next(), parts.next()) {
+        (Some(first), Some(second)) => Ok((
+            first.trim().parse().map_err(ParseError::InvalidComponent)?,
+            second.trim().parse().map_err(ParseError::InvalidComponent)?,
+        )),
+        _ => Err(ParseError::MissingSeparator),
+    }
+}
+
+/// Parses a point written as `"x,y"`
+impl<T: FromStr, Unit> FromStr for Point<T, Unit> {
+    type Err = ParseError<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = parse_pair(s, &[','])?;
+        Ok(Point::new(PosX::new(x), PosY::new(y)))
+    }
+}
+
+/// Parses a size written as either `"w,h"` or `"wxh"`
+impl<T: FromStr, Unit> FromStr for Size<T, Unit> {
+    type Err = ParseError<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (width, height) = parse_pair(s, &[',', 'x'])?;
+        Ok(Size::new(Width::new(width), Height::new(height)))
+    }
+}
+
+/// One of the two coordinate axes
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Axis {
+    /// The x axis, along which widths are measured
+    Horizontal,
+    /// The y axis, along which heights are measured
+    Vertical,
+}
+
+/// The ratio of width to height, independent of the unit
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+pub struct AspectRatio<T>(T);
+
+impl<T> AspectRatio<T> {
+    pub fn new(ratio: T) -> Self {
+        AspectRatio(ratio)
+    }
+
+    /// Returns the aspect ratio of `size`
+    pub fn from_size<Unit>(size: &Size<T, Unit>) -> Self
+    where
+        T: Clone + Div<T, Output = T>,
+    {
+        AspectRatio(size.width.get() / size.height.get())
+    }
+
+    /// Returns the width divided by the height
+    pub fn get(&self) -> T
+    where
+        T: Clone,
+    {
+        self.0.clone()
+    }
+
+    /// Returns the height that keeps this aspect ratio for the given width
+    pub fn height_for_width<Unit>(&self, width: Width<T, Unit>) -> Height<T, Unit>
+    where
+        T: Clone + Div<T, Output = T>,
+    {
+        Height::new(width.into_inner() / self.get())
+    }
+
+    /// Returns the width that keeps this aspect ratio for the given height
+    pub fn width_for_height<Unit>(&self, height: Height<T, Unit>) -> Width<T, Unit>
+    where
+        T: Clone + Mul<T, Output = T>,
+    {
+        Width::new(height.into_inner() * self.get())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    pub use super::*;
+    use test_util::*;
+
+    #[test]
+    fn construct_size() {
+        let w: Width<f64, Pixel> = Width::new(40.0);
+        let h: Height<f64, Pixel> = Height::new(20.0);
+        let size = Size {
+            width: w,
+            height: h,
+        };
+
+        assert_eq!(
+            size,
+            Size {
+                width: Width::<f64, Pixel>::new(40.0),
+                height: Height::<f64, Pixel>::new(20.0),
+            }
+        );
+    }
+
+    #[test]
+    #[allow(clippy::clone_on_copy)]
+    fn size_is_clone() {
+        let size = Size {
+            width: Width::<f64, Pixel>::new(40.0),
+            height: Height::<f64, Pixel>::new(20.0),
+        };
+        let size_clone = size.clone();
+
+        assert_eq!(size, size_clone);
+    }
+
+    #[test]
+    fn size_is_copy() {
+        let size = Size {
+            width: Width::<f64, Pixel>::new(40.0),
+            height: Height::<f64, Pixel>::new(20.0),
+        };
+        let size_copy = size;
+
+        assert_eq!(size, size_copy);
+    }
+
+    #[test]
+    fn wrap_within() {
+        let world = rect(0.0, 0.0, 100.0, 100.0);
+        assert_eq!(point(101.0, 50.0).wrap_within(&world), point(1.0, 50.0));
+        assert_eq!(point(-1.0, 50.0).wrap_within(&world), point(99.0, 50.0));
+        assert_eq!(point(50.0, 100.5).wrap_within(&world), point(50.0, 0.5));
+        assert_eq!(point(50.0, -0.5).wrap_within(&world), point(50.0, 99.5));
+        assert_eq!(point(30.0, 40.0).wrap_within(&world), point(30.0, 40.0));
+    }
+
+    #[test]
+    fn wrap_within_zero_extent() {
+        assert_eq!(point(7.0, 50.0).wrap_within(&rect(5.0, 0.0, 0.0, 100.0)), point(5.0, 50.0));
+        assert_eq!(
+            point(7.0, 50.0).confine(&rect(0.0, 10.0, 100.0, 0.0), ConfineMode::Wrap),
+            point(7.0, 10.0)
+        );
+
+        let line = int_rect(3, 0, 0, 10);
+        let p: Point<i32, Pixel> = Point::new(PosX::new(12), PosY::new(14));
+        assert_eq!(p.wrap_within(&line), Point::new(PosX::new(3), PosY::new(4)));
+        let modulus = Size::new(Width::new(0), Height::new(5));
+        assert_eq!(p % modulus, Point::new(PosX::new(0), PosY::new(4)));
+    }
+
+    #[test]
+    fn mirror_origin() {
+        assert_eq!(rect(10.0, 10.0, 5.0, 5.0).mirror_origin(), rect(-15.0, -15.0, 5.0, 5.0));
+    }
+
+    #[test]
+    fn checked_and_saturating_add() {
+        let p: Point<i32, Pixel> = Point::new(PosX::new(i32::MAX - 1), PosY::new(0));
+        let offset = Size::new(Width::new(5), Height::new(5));
+        assert_eq!(p.checked_add(&offset), None);
+        assert_eq!(
+            p.saturating_add(&offset),
+            Point::new(PosX::new(i32::MAX), PosY::new(5))
+        );
+
+        let s: Size<i32, Pixel> = Size::new(Width::new(1), Height::new(i32::MIN));
+        let shrink = Size::new(Width::new(1), Height::new(-1));
+        assert_eq!(s.checked_add(&shrink), None);
+        assert_eq!(
+            s.saturating_add(&shrink),
+            Size::new(Width::new(2), Height::new(i32::MIN))
+        );
+    }
+
+    #[test]
+    fn aspect_ratio() {
+        let ratio = AspectRatio::from_size(&size(16.0, 9.0));
+        assert_eq!(ratio.height_for_width(Width::<f64, Pixel>::new(16.0)), Height::new(9.0));
+        assert_eq!(ratio.width_for_height(Height::<f64, Pixel>::new(9.0)), Width::new(16.0));
+    }
+
+    #[test]
+    fn sort_by_area() {
+        let mut rects = vec![
+            rect(0.0, 0.0, 2.0, 3.0),
+            rect(5.0, 5.0, 4.0, 4.0),
+            rect(-1.0, 0.0, 1.0, 1.0),
+        ];
+        rects.sort_by(|a, b| b.area_cmp(a).unwrap());
+
+        assert_eq!(
+            rects,
+            vec![
+                rect(5.0, 5.0, 4.0, 4.0),
+                rect(0.0, 0.0, 2.0, 3.0),
+                rect(-1.0, 0.0, 1.0, 1.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn point_component_min_max() {
+        assert_eq!(point(1.0, 5.0).component_min(&point(3.0, 2.0)), point(1.0, 2.0));
+        assert_eq!(point(1.0, 5.0).component_max(&point(3.0, 2.0)), point(3.0, 5.0));
+
+        // Integer points are also totally ordered, which must not get in the way
+        let a: Point<i32, Pixel> = Point::new(PosX::new(1), PosY::new(5));
+        let b: Point<i32, Pixel> = Point::new(PosX::new(3), PosY::new(2));
+        assert_eq!(a.component_min(&b), Point::new(PosX::new(1), PosY::new(2)));
+        assert_eq!(a.component_max(&b), Point::new(PosX::new(3), PosY::new(5)));
+        assert_eq!(a.min(b), a);
+    }
+
+    #[test]
+    fn bounding() {
+        let points = vec![point(1.0, 5.0), point(3.0, 2.0), point(-1.0, 4.0)];
+        assert_eq!(Rect::bounding(points), Some(rect(-1.0, 2.0, 4.0, 3.0)));
+        assert_eq!(Rect::bounding(Vec::<Point<f64, Pixel>>::new()), None);
+    }
+
+    #[test]
+    fn expand_to_include() {
+        let mut r = rect(0.0, 0.0, 2.0, 2.0);
+        r.expand_to_include(&point(5.0, -1.0));
+        assert_eq!(r, rect(0.0, -1.0, 5.0, 3.0));
+    }
+
+    #[test]
+    fn tile() {
+        let tiles: Vec<_> = rect(0.0, 0.0, 100.0, 50.0).tile(size(40.0, 40.0)).collect();
+        assert_eq!(
+            tiles,
+            vec![
+                rect(0.0, 0.0, 40.0, 40.0),
+                rect(40.0, 0.0, 40.0, 40.0),
+                rect(80.0, 0.0, 20.0, 40.0),
+                rect(0.0, 40.0, 40.0, 10.0),
+                rect(40.0, 40.0, 40.0, 10.0),
+                rect(80.0, 40.0, 20.0, 10.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn fold() {
+        assert_eq!(size(3.0, 4.0).fold(0.0, |sum, c| sum + c), 7.0);
+        assert_eq!(point(3.0, 4.0).fold(vec![], |mut v, c| { v.push(c); v }), vec![3.0, 4.0]);
+        assert_eq!(rect(1.0, 2.0, 3.0, 4.0).fold(0.0, f64::max), 4.0);
+    }
+
+    #[test]
+    fn zip_with() {
+        assert_eq!(size(2.0, 3.0).zip_with(&size(4.0, 5.0), |a, b| a * b), size(8.0, 15.0));
+        assert_eq!(point(1.0, 7.0).zip_with(&point(4.0, 2.0), f64::min), point(1.0, 2.0));
+    }
+
+    #[test]
+    fn parse_point() {
+        assert_eq!("3,4".parse::<Point<f64, Pixel>>(), Ok(point(3.0, 4.0)));
+        assert_eq!(" 3 , -4.5 ".parse::<Point<f64, Pixel>>(), Ok(point(3.0, -4.5)));
+        assert!(matches!(
+            "3,".parse::<Point<f64, Pixel>>(),
+            Err(ParseError::InvalidComponent(_))
+        ));
+        assert_eq!("3".parse::<Point<f64, Pixel>>(), Err(ParseError::MissingSeparator));
+        assert_eq!(
+            ParseError::<std::num::ParseFloatError>::MissingSeparator.to_string(),
+            "expected two components separated by ',' (or 'x' for a size)"
+        );
+    }
+
+    #[test]
+    fn parse_size() {
+        assert_eq!("640x480".parse::<Size<f64, Pixel>>(), Ok(size(640.0, 480.0)));
+        assert_eq!("640,480".parse::<Size<f64, Pixel>>(), Ok(size(640.0, 480.0)));
+    }
+
+    #[test]
+    fn lerp_and_distance() {
+        assert_eq!(point(0.0, 10.0).lerp(&point(4.0, 2.0), 0.25), point(1.0, 8.0));
+        assert_eq!(point(1.0, 1.0).distance_to(&point(4.0, 5.0)), Length::new(5.0));
+    }
+
+    #[test]
+    fn lerp_clamped() {
+        let (a, b) = (point(0.1, 10.0), point(4.0, 2.3));
+        assert_eq!(a.lerp_clamped(&b, -0.5), a);
+        assert_eq!(a.lerp_clamped(&b, 1.5), b);
+        assert_eq!(a.lerp_clamped(&b, 0.5), a.lerp(&b, 0.5));
+    }
+
+    #[test]
+    fn smoothstep() {
+        let (a, b) = (point(0.0, 10.0), point(4.0, 2.0));
+        assert_eq!(a.smoothstep(&b, 0.5), a.lerp(&b, 0.5));
+        assert_eq!(a.smoothstep(&b, -1.0), a);
+        assert_eq!(a.smoothstep(&b, 2.0), b);
+
+        // The slope is zero at both ends, so a small step moves much less than linearly
+        let eps = 1e-4;
+        let start = a.smoothstep(&b, eps).distance_to(&a).get();
+        let end = b.smoothstep(&a, eps).distance_to(&b).get();
+        let linear = a.lerp(&b, eps).distance_to(&a).get();
+        assert!(start < linear * 1e-3 && end < linear * 1e-3);
+
+        let (small, large) = (size(2.0, 4.0), size(6.0, 0.0));
+        assert_eq!(small.smoothstep(&large, 0.5), size(4.0, 2.0));
+        assert_eq!(small.smoothstep(&large, 1.0), large);
+    }
+
+    #[test]
+    fn contains() {
+        let r = rect(0.0, 0.0, 10.0, 5.0);
+        assert!(r.contains(&point(3.0, 2.0)));
+        assert!(r.contains(&point(10.0, 5.0)));
+        assert!(!r.contains(&point(10.5, 2.0)));
+        assert!(!r.contains(&point(3.0, -0.1)));
+    }
+
+    #[test]
+    fn size_point_conversions() {
+        assert_eq!(size(3.0, -4.0).to_point_offset(), point(3.0, -4.0));
+        assert_eq!(point(3.0, -4.0).to_size_from_origin(), size(3.0, -4.0));
+    }
+
+    #[test]
+    fn rect_map_unit() {
+        struct Other;
+        let retagged: Rect<f64, Other> = rect(1.0, 2.0, 3.0, 4.0).map_unit();
+        assert_eq!(retagged.map_unit::<Pixel>(), rect(1.0, 2.0, 3.0, 4.0));
+        assert_eq!(retagged.size.width, Width::<f64, Other>::new(3.0));
+    }
+
+    #[test]
+    fn corners() {
+        let r = rect(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(r.corners(), [point(1.0, 2.0), point(4.0, 2.0), point(4.0, 6.0), point(1.0, 6.0)]);
+        assert_eq!(r.corner_iter().collect::<Vec<_>>(), r.corners().to_vec());
+    }
+
+    #[test]
+    fn to_int_outward() {
+        assert_eq!(rect(0.5, 1.25, 2.0, 2.5).to_int(RoundMode::Outward), int_rect(0, 1, 3, 3));
+    }
+
+    #[test]
+    fn to_int_inward() {
+        assert_eq!(rect(0.5, 1.25, 2.0, 2.5).to_int(RoundMode::Inward), int_rect(1, 2, 1, 1));
+        assert_eq!(rect(0.2, 0.2, 0.5, 0.5).to_int(RoundMode::Inward), int_rect(1, 1, 0, 0));
+    }
+
+    #[test]
+    fn to_int_floor_ceil_round() {
+        let r = rect(0.5, 1.25, 2.0, 2.5);
+        assert_eq!(r.to_int(RoundMode::Floor), int_rect(0, 1, 2, 2));
+        assert_eq!(r.to_int(RoundMode::Ceil), int_rect(1, 2, 2, 2));
+        assert_eq!(r.to_int(RoundMode::Round), int_rect(1, 1, 2, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "rect size out of i32 range")]
+    fn to_int_size_overflow() {
+        rect(-2e9, 0.0, 4e9, 1.0).to_int(RoundMode::Outward);
+    }
+
+    #[test]
+    fn rotated_bounds() {
+        let assert_near = |a: Size<f64, Pixel>, b: Size<f64, Pixel>| {
+            assert!((a.width.get() - b.width.get()).abs() < 1e-9, "{:?} != {:?}", a, b);
+            assert!((a.height.get() - b.height.get()).abs() < 1e-9, "{:?} != {:?}", a, b);
+        };
+        assert_near(size(10.0, 0.0).rotated_bounds(Angle::from_degrees(90.0)), size(0.0, 10.0));
+        assert_near(size(10.0, 0.0).rotated_bounds(Angle::from_degrees(-90.0)), size(0.0, 10.0));
+        assert_near(size(10.0, 4.0).rotated_bounds(Angle::from_degrees(180.0)), size(10.0, 4.0));
+
+        let diagonal = 10.0 * 2.0_f64.sqrt();
+        assert_near(
+            size(10.0, 10.0).rotated_bounds(Angle::from_degrees(45.0)),
+            size(diagonal, diagonal),
+        );
+    }
+
+    #[test]
+    fn size_components() {
+        let s = size(3.0, -4.0);
+        assert_eq!(s.width_component(), size(3.0, 0.0));
+        assert_eq!(s.height_component(), size(0.0, -4.0));
+        assert_eq!(s.width_component() + s.height_component(), s);
+        assert_eq!(point(1.0, 1.0) + s.width_component(), point(4.0, 1.0));
+    }
+
+    #[test]
+    fn size_abs() {
+        assert_eq!(size(-4.0, 3.0).abs(), size(4.0, 3.0));
+    }
+
+    #[test]
+    fn bilinear_remap() {
+        let from = rect(0.0, 0.0, 10.0, 20.0);
+        let quad = [point(0.0, 0.0), point(8.0, 2.0), point(10.0, 12.0), point(-2.0, 6.0)];
+
+        assert_eq!(point(5.0, 10.0).bilinear_remap(&from, &quad), point(4.0, 5.0));
+        assert_eq!(point(10.0, 20.0).bilinear_remap(&from, &quad), point(10.0, 12.0));
+    }
+
+    #[test]
+    fn checked_corner() {
+        assert_eq!(
+            int_rect(10, 20, 5, 6).checked_corner(),
+            Some(Point::new(PosX::new(15), PosY::new(26)))
+        );
+        assert_eq!(int_rect(i32::MAX - 1, 0, 2, 1).checked_corner(), None);
+    }
+
+    #[test]
+    fn bounds_accumulator() {
+        let points = vec![point(3.0, -1.0), point(-2.0, 4.0), point(5.0, 2.0)];
+
+        let mut bounds = BoundsAccumulator::new();
+        assert!(bounds.clone().finish().is_none());
+        for p in points.iter() {
+            bounds.push(*p);
+        }
+
+        assert_eq!(bounds.finish(), Rect::bounding(points));
+    }
+
+    #[test]
+    fn overlap_per_axis() {
+        let a = rect(0.0, 0.0, 10.0, 10.0);
+        let b = rect(6.0, 20.0, 10.0, 5.0);
+
+        assert_eq!(a.overlap_x(&b), Some(Interval::new(Position::new(6.0), Position::new(10.0))));
+        assert_eq!(a.overlap_y(&b), None);
+        assert_eq!(a.overlap_y(&rect(0.0, 10.0, 1.0, 1.0)), None);
+    }
+
+    #[test]
+    fn sweep() {
+        let moving = rect(0.0, 0.0, 10.0, 10.0);
+        let velocity = size(20.0, 0.0);
+
+        assert_eq!(moving.sweep(velocity, &rect(20.0, 5.0, 10.0, 10.0)), Some(0.5));
+        assert_eq!(moving.sweep(velocity, &rect(20.0, 50.0, 10.0, 10.0)), None);
+        assert_eq!(moving.sweep(velocity, &rect(40.0, 0.0, 10.0, 10.0)), None);
+        assert_eq!(moving.sweep(size(0.0, 0.0), &rect(5.0, 5.0, 10.0, 10.0)), Some(0.0));
+    }
+
+    #[test]
+    fn minimum_translation_vector() {
+        let a = rect(0.0, 0.0, 10.0, 10.0);
+        let b = rect(7.0, 5.0, 10.0, 10.0);
+
+        assert_eq!(a.minimum_translation_vector(&b), Some(size(-3.0, 0.0)));
+        assert_eq!(b.minimum_translation_vector(&a), Some(size(3.0, 0.0)));
+        assert_eq!(a.minimum_translation_vector(&rect(20.0, 0.0, 1.0, 1.0)), None);
+    }
+
+    #[test]
+    fn partial_cmp_components() {
+        assert_eq!(
+            size(20.0, 5.0).partial_cmp_components(&size(10.0, 8.0)),
+            (Some(Ordering::Greater), Some(Ordering::Less))
+        );
+        assert_eq!(
+            size(10.0, 5.0).partial_cmp_components(&size(10.0, f64::NAN)),
+            (Some(Ordering::Equal), None)
+        );
+    }
+
+    #[test]
+    fn inset_single_side() {
+        let r = rect(0.0, 0.0, 100.0, 50.0);
+        assert_eq!(r.inset_top(Height::new(10.0)), rect(0.0, 10.0, 100.0, 40.0));
+        assert_eq!(r.inset_left(Width::new(10.0)), rect(10.0, 0.0, 90.0, 50.0));
+        assert_eq!(r.inset_right(Width::new(10.0)), rect(0.0, 0.0, 90.0, 50.0));
+        assert_eq!(r.inset_bottom(Height::new(10.0)), rect(0.0, 0.0, 100.0, 40.0));
+        assert_eq!(
+            r.inset_top(Height::new(5.0)).inset_left(Width::new(20.0)),
+            rect(20.0, 5.0, 80.0, 45.0)
+        );
+    }
+
+    #[test]
+    fn confine() {
+        let world = rect(0.0, 0.0, 100.0, 100.0);
+        assert_eq!(point(110.0, 50.0).confine(&world, ConfineMode::Clamp), point(100.0, 50.0));
+        assert_eq!(point(110.0, 50.0).confine(&world, ConfineMode::Wrap), point(10.0, 50.0));
+        assert_eq!(point(30.0, -5.0).confine(&world, ConfineMode::Clamp), point(30.0, 0.0));
+    }
+
+    #[test]
+    fn union_non_empty() {
+        let real = rect(10.0, 20.0, 5.0, 5.0);
+        let empty = rect(0.0, 0.0, 0.0, 0.0);
+
+        assert_eq!(real.union(&empty), rect(0.0, 0.0, 15.0, 25.0));
+        assert_eq!(real.union_non_empty(&empty), real);
+        assert_eq!(empty.union_non_empty(&real), real);
+        assert_eq!(real.union_non_empty(&rect(0.0, 0.0, 1.0, 1.0)), rect(0.0, 0.0, 15.0, 25.0));
+    }
+
+    #[test]
+    fn border_cells() {
+        let cells: Vec<_> = int_rect(0, 0, 3, 3)
+            .border_cells()
+            .map(|p| (p.x.get(), p.y.get()))
+            .collect();
+        assert_eq!(cells, vec![(0, 0), (1, 0), (2, 0), (0, 1), (2, 1), (0, 2), (1, 2), (2, 2)]);
+
+        assert_eq!(int_rect(5, 5, 4, 1).border_cells().count(), 4);
+        assert_eq!(int_rect(5, 5, 1, 4).border_cells().count(), 4);
+        assert_eq!(int_rect(5, 5, 0, 4).border_cells().count(), 0);
+        assert_eq!(int_rect(i32::MIN, i32::MIN, 0, 0).border_cells().count(), 0);
+
+        let far: Vec<_> = int_rect(i32::MAX - 1, i32::MAX - 2, 2, 3).border_cells().collect();
+        assert_eq!(far.len(), 6);
+        assert_eq!(far[5], Point::new(PosX::new(i32::MAX), PosY::new(i32::MAX)));
+    }
+
+    #[test]
+    fn spiral_cells() {
+        let cells: Vec<_> = int_rect(0, 0, 3, 3).spiral_cells().collect();
+        assert_eq!(cells.len(), 9);
+        let cells: Vec<_> = cells.iter().map(|p| (p.x.get(), p.y.get())).collect();
+        assert_eq!(cells[0], (1, 1));
+        assert_eq!(cells[1..5], [(2, 1), (1, 2), (0, 1), (1, 0)]);
+        assert_eq!(cells[5..], [(2, 2), (0, 2), (0, 0), (2, 0)]);
+
+        let mut cells: Vec<_> = int_rect(-2, 3, 4, 1).spiral_cells().collect();
+        assert_eq!(cells[0], Point::new(PosX::new(0), PosY::new(3)));
+        cells.sort();
+        assert_eq!(cells, int_rect(-2, 3, 4, 1).border_cells().collect::<Vec<_>>());
+        assert_eq!(int_rect(0, 0, 0, 3).spiral_cells().count(), 0);
+    }
+
+    #[test]
+    fn spiral_cells_long_and_thin() {
+        let cells: Vec<_> = int_rect(10, -5, 1, 100_000).spiral_cells().collect();
+        assert_eq!(cells.len(), 100_000);
+        assert_eq!(cells[0], Point::new(PosX::new(10), PosY::new(49_995)));
+        assert_eq!(cells[1], Point::new(PosX::new(10), PosY::new(49_996)));
+        assert_eq!(cells[2], Point::new(PosX::new(10), PosY::new(49_994)));
+        assert_eq!(cells[99_999], Point::new(PosX::new(10), PosY::new(-5)));
+
+        let near_max = int_rect(i32::MAX - 2, i32::MAX - 1, 2, 1).spiral_cells().count();
+        assert_eq!(near_max, 2);
+
+        let huge = int_rect(i32::MIN, i32::MIN, i32::MAX, i32::MAX);
+        assert_eq!(huge.spiral_cells().take(9).count(), 9);
+    }
+
+    #[test]
+    fn scale_non_uniform_about_center() {
+        let r = rect(10.0, 20.0, 40.0, 60.0);
+        let scaled = r.scale_non_uniform_about_center(size(2.0, 0.5));
+
+        assert_eq!(scaled, rect(-10.0, 35.0, 80.0, 30.0));
+        assert_eq!(scaled.origin + scaled.size / 2.0, r.origin + r.size / 2.0);
+    }
+
+    #[test]
+    fn shrink_to_aspect() {
+        let r = rect(0.0, 0.0, 100.0, 100.0);
+        assert_eq!(r.shrink_to_aspect(AspectRatio::new(16.0 / 9.0)), rect(0.0, 21.875, 100.0, 56.25));
+        assert_eq!(r.shrink_to_aspect(AspectRatio::new(0.5)), rect(25.0, 0.0, 50.0, 100.0));
+        assert_eq!(r.shrink_to_aspect(AspectRatio::new(1.0)), r);
+    }
+
+    #[test]
+    fn signed_distance() {
+        let r = rect(0.0, 0.0, 20.0, 10.0);
+        assert_eq!(r.signed_distance(&point(5.0, 4.0)), Length::new(-4.0));
+        assert_eq!(r.signed_distance(&point(20.0, 5.0)), Length::new(0.0));
+        assert_eq!(r.signed_distance(&point(23.0, 14.0)), Length::new(5.0));
+        assert_eq!(r.signed_distance(&point(10.0, -2.0)), Length::new(2.0));
+    }
+
+    #[test]
+    fn columns() {
+        let r = rect(0.0, 5.0, 100.0, 20.0);
+        let columns = r.columns(3, Width::new(10.0));
+
+        assert_eq!(columns.len(), 3);
+        for (i, column) in columns.iter().enumerate() {
+            assert!((column.size.width.get() - 80.0 / 3.0).abs() < 1e-9);
+            assert!((column.origin.x.get() - i as f64 * (80.0 / 3.0 + 10.0)).abs() < 1e-9);
+            assert_eq!(column.origin.y, PosY::new(5.0));
+            assert_eq!(column.size.height, Height::new(20.0));
+        }
+        assert_eq!(columns[0].origin.x, r.origin.x);
+        assert_eq!(columns[2].corner().x, r.corner().x);
+        assert!(r.columns(0, Width::new(10.0)).is_empty());
+        assert_eq!(r.columns(1, Width::new(10.0)), vec![r]);
+    }
+
+    #[test]
+    fn rows() {
+        let r = rect(5.0, 0.0, 30.0, 80.0);
+        let rows = r.rows(4, Height::new(5.0));
+
+        assert_eq!(
+            rows,
+            vec![
+                rect(5.0, 0.0, 30.0, 16.25),
+                rect(5.0, 21.25, 30.0, 16.25),
+                rect(5.0, 42.5, 30.0, 16.25),
+                rect(5.0, 63.75, 30.0, 16.25),
+            ]
+        );
+        assert!(r.rows(0, Height::new(5.0)).is_empty());
+    }
+
+    #[test]
+    fn inflate() {
+        assert_eq!(
+            rect(10.0, 10.0, 20.0, 20.0).inflate(Width::new(2.0), Height::new(-1.0)),
+            rect(8.0, 11.0, 24.0, 18.0)
+        );
+    }
+
+    #[test]
+    fn contains_with_tolerance() {
+        let button = rect(0.0, 0.0, 10.0, 10.0);
+        let touch = point(11.5, 5.0);
+
+        assert!(!button.contains(&touch));
+        assert!(button.contains_with_tolerance(&touch, Length::new(2.0)));
+        assert!(!button.contains_with_tolerance(&touch, Length::new(1.0)));
+    }
+
+    #[test]
+    fn constrain_to_aspect() {
+        let ratio = AspectRatio::new(16.0 / 9.0);
+        let dragged = size(320.0, 100.0);
+
+        assert_eq!(dragged.constrain_to_aspect(ratio, Axis::Horizontal), size(320.0, 180.0));
+        assert_eq!(dragged.constrain_to_aspect(ratio, Axis::Vertical), size(1600.0 / 9.0, 100.0));
+    }
+
+    #[test]
+    fn center_floor() {
+        assert_eq!(int_rect(0, 0, 5, 4).center_floor(), Point::new(PosX::new(2), PosY::new(2)));
+        assert_eq!(
+            int_rect(i32::MAX - 10, i32::MIN, 10, 5).center_floor(),
+            Point::new(PosX::new(i32::MAX - 5), PosY::new(i32::MIN + 2))
+        );
+    }
+
+    #[test]
+    fn empty_at() {
+        let mut bounds = Rect::empty_at(point(5.0, 8.0));
+        assert!(bounds.is_empty());
+
+        bounds.expand_to_include(&point(2.0, 10.0));
+        assert_eq!(bounds, Rect::from_points(point(2.0, 8.0), point(5.0, 10.0)));
+    }
+
+    #[test]
+    fn rem() {
+        assert_eq!(point(23.0, 47.0) % size(10.0, 20.0), point(3.0, 7.0));
+        assert_eq!(point(-3.0, -47.0) % size(10.0, 20.0), point(7.0, 13.0));
+        assert_eq!(size(23.0, 47.0) % size(10.0, 20.0), size(3.0, 7.0));
+    }
+
+    #[test]
+    fn min_max_round_trip() {
+        let r = rect(10.0, 20.0, 5.0, 8.0);
+        let (min, max) = r.to_min_max();
+
+        assert_eq!((min, max), (point(10.0, 20.0), point(15.0, 28.0)));
+        assert_eq!(Rect::from_min_max(min, max), r);
+    }
+
+    #[test]
+    fn penetration() {
+        let a = rect(0.0, 0.0, 10.0, 10.0);
+        let b = rect(7.0, 5.0, 10.0, 10.0);
+
+        assert_eq!(a.penetration(&b), Some(size(3.0, 5.0)));
+        assert_eq!(b.penetration(&a), Some(size(3.0, 5.0)));
+        assert_eq!(a.penetration(&rect(0.0, 20.0, 10.0, 10.0)), None);
     }
 }
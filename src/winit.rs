@@ -0,0 +1,72 @@
+//! Conversions to and from the `winit::dpi` types.
+
+use dpi::{LogicalPosition, LogicalSize, PhysicalPosition, PhysicalSize};
+
+use oned::*;
+use twod::*;
+
+/// The unit of `winit`'s logical (scale-independent) coordinates
+pub struct Logical;
+
+/// The unit of `winit`'s physical (pixel) coordinates
+pub struct Physical;
+
+macro_rules! impl_dpi_conversions {
+    ($unit:ident, $size:ident, $position:ident) => {
+        impl<T> From<Size<T, $unit>> for $size<T> {
+            fn from(s: Size<T, $unit>) -> Self {
+                $size::new(s.width.into_inner(), s.height.into_inner())
+            }
+        }
+
+        impl<T> From<$size<T>> for Size<T, $unit> {
+            fn from(s: $size<T>) -> Self {
+                Size::new(Width::new(s.width), Height::new(s.height))
+            }
+        }
+
+        impl<T> From<Point<T, $unit>> for $position<T> {
+            fn from(p: Point<T, $unit>) -> Self {
+                $position::new(p.x.into_inner(), p.y.into_inner())
+            }
+        }
+
+        impl<T> From<$position<T>> for Point<T, $unit> {
+            fn from(p: $position<T>) -> Self {
+                Point::new(PosX::new(p.x), PosY::new(p.y))
+            }
+        }
+    }
+}
+
+impl_dpi_conversions!(Logical, LogicalSize, LogicalPosition);
+impl_dpi_conversions!(Physical, PhysicalSize, PhysicalPosition);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn logical_size_round_trip() {
+        let size: Size<f64, Logical> = Size::new(Width::new(800.0), Height::new(600.0));
+        let logical: LogicalSize<f64> = size.into();
+        assert_eq!(logical, LogicalSize::new(800.0, 600.0));
+        assert_eq!(Size::from(logical), size);
+    }
+
+    #[test]
+    fn physical_size_round_trip() {
+        let size: Size<u32, Physical> = Size::new(Width::new(1600), Height::new(1200));
+        let physical: PhysicalSize<u32> = size.into();
+        assert_eq!(physical, PhysicalSize::new(1600, 1200));
+        assert_eq!(Size::from(physical), size);
+    }
+
+    #[test]
+    fn position_round_trip() {
+        let point: Point<f64, Logical> = Point::new(PosX::new(10.5), PosY::new(-3.0));
+        let logical: LogicalPosition<f64> = point.into();
+        assert_eq!(logical, LogicalPosition::new(10.5, -3.0));
+        assert_eq!(Point::from(logical), point);
+    }
+}